@@ -0,0 +1,153 @@
+//! Discovers the solutions in `src/years/yYYYY/dayNN.rs` and generates their module
+//! declarations and the registry of the runner.
+
+use std::{env, fs, path::Path, process};
+
+const YEARS_DIR: &str = "src/years";
+
+/// A solution file found below `YEARS_DIR`
+struct DayModule {
+    year: u16,
+    day: u8,
+    path: String,
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed={}", YEARS_DIR);
+
+    let days = discover_days(Path::new(YEARS_DIR));
+    let missing: Vec<String> = days
+        .iter()
+        .filter_map(|module| check_entry_point(module).err())
+        .collect();
+    if !missing.is_empty() {
+        for message in &missing {
+            eprintln!("error: {}", message);
+        }
+        process::exit(1);
+    }
+
+    let out_dir = env::var("OUT_DIR").expect("OUT_DIR is set by cargo");
+    write(&out_dir, "years.rs", &module_declarations(&days));
+    write(&out_dir, "registry.rs", &registry(&days));
+}
+
+/// Days with a `yYYYY/dayNN.rs` file, sorted by year and day; `day0.rs` is the template
+fn discover_days(dir: &Path) -> Vec<DayModule> {
+    let mut days = Vec::new();
+    for (year, year_dir) in list_dir(dir, parse_year_dir_name) {
+        for (day, path) in list_dir(&year_dir, parse_day_file_name) {
+            let path = fs::canonicalize(&path).unwrap_or(path);
+            days.push(DayModule {
+                year,
+                day,
+                path: path.to_string_lossy().into_owned(),
+            });
+        }
+    }
+    days.sort_by_key(|module| (module.year, module.day));
+    days
+}
+
+/// Entries of `dir` whose file name is accepted by `parse`
+fn list_dir<T>(dir: &Path, parse: fn(&str) -> Option<T>) -> Vec<(T, std::path::PathBuf)> {
+    let entries = fs::read_dir(dir).unwrap_or_else(|err| panic!("cannot read {:?}: {}", dir, err));
+    entries
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| {
+            let name = entry.file_name().into_string().ok()?;
+            Some((parse(&name)?, entry.path()))
+        })
+        .collect()
+}
+
+/// Parses "y2025" into 2025, other names are not year directories
+fn parse_year_dir_name(name: &str) -> Option<u16> {
+    let digits = name.strip_prefix('y')?;
+    if digits.len() != 4 || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    digits.parse().ok()
+}
+
+/// Parses "day07.rs" into 7, other names are not day modules
+fn parse_day_file_name(name: &str) -> Option<u8> {
+    let digits = name.strip_prefix("day")?.strip_suffix(".rs")?;
+    if digits.len() != 2 || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    digits.parse().ok().filter(|&day| day > 0)
+}
+
+fn module_name(day: u8) -> String {
+    format!("day{:02}", day)
+}
+
+fn struct_name(day: u8) -> String {
+    format!("Day{:02}", day)
+}
+
+/// Every day module has to export `DayNN` implementing `Solution`
+fn check_entry_point(module: &DayModule) -> Result<(), String> {
+    let path = &module.path;
+    let source =
+        fs::read_to_string(path).map_err(|err| format!("cannot read {}: {}", path, err))?;
+    let name = struct_name(module.day);
+
+    let declares_struct = source.contains(&format!("pub struct {};", name))
+        || source.contains(&format!("pub struct {} ", name));
+    let implements_solution = source.contains(&format!("impl Solution for {} ", name));
+    match declares_struct && implements_solution {
+        true => Ok(()),
+        false => Err(format!(
+            "{} has to declare `pub struct {}` and `impl Solution for {}`",
+            path, name, name
+        )),
+    }
+}
+
+/// One `pub mod yYYYY { pub mod dayNN; ... }` per year
+fn module_declarations(days: &[DayModule]) -> String {
+    let mut declarations = String::new();
+    for (i, module) in days.iter().enumerate() {
+        if i == 0 || days[i - 1].year != module.year {
+            declarations.push_str(&format!("pub mod y{} {{\n", module.year));
+        }
+        declarations.push_str(&format!(
+            "    #[path = {:?}]\n    pub mod {};\n",
+            module.path,
+            module_name(module.day)
+        ));
+        if i + 1 == days.len() || days[i + 1].year != module.year {
+            declarations.push_str("}\n");
+        }
+    }
+    declarations
+}
+
+fn registry(days: &[DayModule]) -> String {
+    let entries: String = days
+        .iter()
+        .map(|module| {
+            let solution = format!(
+                "years::y{}::{}::{}",
+                module.year,
+                module_name(module.day),
+                struct_name(module.day)
+            );
+            format!(
+                "    ({}, {}, run::<{}>, bench::bench::<{}>),\n",
+                module.year, module.day, solution, solution
+            )
+        })
+        .collect();
+    format!(
+        "const AVAILABLE_DAYS: &[(u16, u8, Runner, Bencher)] = &[\n{}];\n",
+        entries
+    )
+}
+
+fn write(out_dir: &str, file: &str, content: &str) {
+    let path = Path::new(out_dir).join(file);
+    fs::write(&path, content).unwrap_or_else(|err| panic!("cannot write {:?}: {}", path, err));
+}
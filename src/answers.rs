@@ -0,0 +1,307 @@
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::types::answer::Answer;
+
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+/// `answers.toml`, relative to the working directory
+pub fn default_answers_file() -> PathBuf {
+    PathBuf::from(ANSWERS_FILE)
+}
+
+/// Outcome of comparing a computed answer with the known one
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verification {
+    Correct,
+    Wrong(String),
+    Unknown,
+}
+
+impl Verification {
+    pub fn symbol(&self) -> char {
+        match self {
+            Verification::Correct => '✓',
+            Verification::Wrong(_) => '✗',
+            Verification::Unknown => '?',
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Verification::Correct => "correct",
+            Verification::Wrong(_) => "wrong",
+            Verification::Unknown => "unknown",
+        }
+    }
+}
+
+/// Known answers keyed by year, day, input name and part.
+///
+/// The file uses a small subset of TOML with one table per year, day and input:
+///
+/// ```toml
+/// [2025.day01.input]
+/// part1 = 1071
+/// part2 = 6700
+///
+/// [2025.day01.example]
+/// part1 = 3
+/// ```
+#[derive(Debug, Default)]
+pub struct KnownAnswers {
+    answers: HashMap<(u16, u8, String, u8), String>,
+}
+
+impl KnownAnswers {
+    /// Loads the answers file, a missing file means no known answers
+    pub fn load(path: &Path) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Ok(content) => Self::parse(&content)
+                .map_err(|err| format!("invalid answers file {}: {}", path.display(), err)),
+            Err(_) => Ok(Self::default()),
+        }
+    }
+
+    pub fn parse(content: &str) -> Result<Self, String> {
+        let mut answers = HashMap::new();
+        let mut section: Option<(u16, u8, String)> = None;
+
+        for (index, raw_line) in content.lines().enumerate() {
+            let line_number = index + 1;
+            let line = strip_comment(raw_line).trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            if let Some(header) = line.strip_prefix('[') {
+                let header = header
+                    .strip_suffix(']')
+                    .ok_or(format!("line {}: unclosed section header", line_number))?;
+                section = Some(parse_section(header).ok_or(format!(
+                    "line {}: expected [YYYY.dayNN.input], found [{}]",
+                    line_number, header
+                ))?);
+                continue;
+            }
+
+            let (year, day, input) = section
+                .clone()
+                .ok_or(format!("line {}: key outside of a section", line_number))?;
+            let (key, value) = line
+                .split_once('=')
+                .ok_or(format!("line {}: expected key = value", line_number))?;
+            let part = key
+                .trim()
+                .strip_prefix("part")
+                .and_then(|p| p.parse::<u8>().ok())
+                .ok_or(format!(
+                    "line {}: expected part1 or part2, found {}",
+                    line_number,
+                    key.trim()
+                ))?;
+            let value = parse_value(value.trim()).ok_or(format!(
+                "line {}: invalid value {}",
+                line_number,
+                value.trim()
+            ))?;
+
+            answers.insert((year, day, input, part), value);
+        }
+
+        Ok(KnownAnswers { answers })
+    }
+
+    pub fn get(&self, year: u16, day: u8, input: &str, part: u8) -> Option<&str> {
+        self.answers
+            .get(&(year, day, input.to_string(), part))
+            .map(String::as_str)
+    }
+
+    pub fn verify(
+        &self,
+        year: u16,
+        day: u8,
+        input: &str,
+        part: u8,
+        answer: &Answer,
+    ) -> Verification {
+        match self.get(year, day, input, part) {
+            Some(expected) if expected == answer.to_string() => Verification::Correct,
+            Some(expected) => Verification::Wrong(expected.to_string()),
+            None => Verification::Unknown,
+        }
+    }
+}
+
+/// Removes a trailing `#` comment unless it is part of a quoted string
+pub(crate) fn strip_comment(line: &str) -> &str {
+    let mut in_string = false;
+    for (i, ch) in line.char_indices() {
+        match ch {
+            '"' => in_string = !in_string,
+            '#' if !in_string => return &line[..i],
+            _ => {}
+        }
+    }
+    line
+}
+
+/// Parses "2025.day01.input" into (2025, 1, "input")
+fn parse_section(header: &str) -> Option<(u16, u8, String)> {
+    let (year, rest) = header.trim().split_once('.')?;
+    let (day, input) = rest.split_once('.')?;
+    let year = year.parse().ok()?;
+    let day = day.strip_prefix("day")?.parse().ok()?;
+    if input.is_empty() {
+        return None;
+    }
+    Some((year, day, input.to_string()))
+}
+
+/// Accepts integers (with optional `_` separators) and quoted strings
+pub(crate) fn parse_value(value: &str) -> Option<String> {
+    if let Some(text) = value.strip_prefix('"') {
+        return text.strip_suffix('"').map(str::to_string);
+    }
+    let number = value.replace('_', "");
+    number.parse::<i128>().ok()?;
+    Some(number.trim_start_matches('+').to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = r#"
+# Known answers
+[2025.day01.input]
+part1 = 1071
+part2 = 6_700 # with separator
+
+[2025.day01.example]
+part1 = 3
+
+[2024.day13.input]
+part1 = "EFJKZ"
+part2 = -42
+"#;
+
+    #[test]
+    fn test_parse_sections_and_parts() {
+        let known = KnownAnswers::parse(SAMPLE).unwrap();
+        assert_eq!(known.get(2025, 1, "input", 1), Some("1071"));
+        assert_eq!(known.get(2025, 1, "input", 2), Some("6700"));
+        assert_eq!(known.get(2025, 1, "example", 1), Some("3"));
+        assert_eq!(known.get(2025, 1, "example", 2), None);
+        assert_eq!(known.get(2024, 13, "input", 1), Some("EFJKZ"));
+        assert_eq!(known.get(2024, 13, "input", 2), Some("-42"));
+    }
+
+    #[test]
+    fn test_verify() {
+        let known = KnownAnswers::parse(SAMPLE).unwrap();
+        assert_eq!(
+            known.verify(2025, 1, "input", 1, &Answer::Unsigned(1071)),
+            Verification::Correct
+        );
+        assert_eq!(
+            known.verify(2025, 1, "input", 2, &Answer::Signed(6701)),
+            Verification::Wrong("6700".to_string())
+        );
+        assert_eq!(
+            known.verify(2024, 13, "input", 1, &Answer::from("EFJKZ")),
+            Verification::Correct
+        );
+        assert_eq!(
+            known.verify(2025, 2, "input", 1, &Answer::Unsigned(1)),
+            Verification::Unknown
+        );
+    }
+
+    #[test]
+    fn test_verify_not_implemented_is_wrong_when_known() {
+        let known = KnownAnswers::parse(SAMPLE).unwrap();
+        assert!(matches!(
+            known.verify(2025, 1, "input", 1, &Answer::NotImplemented),
+            Verification::Wrong(_)
+        ));
+    }
+
+    #[test]
+    fn test_parse_empty() {
+        let known = KnownAnswers::parse("").unwrap();
+        assert_eq!(known.get(2025, 1, "input", 1), None);
+    }
+
+    #[test]
+    fn test_parse_key_outside_section() {
+        let err = KnownAnswers::parse("part1 = 5").unwrap_err();
+        assert!(err.contains("line 1"));
+    }
+
+    #[test]
+    fn test_parse_invalid_section() {
+        let err = KnownAnswers::parse("[first]\npart1 = 5").unwrap_err();
+        assert!(err.contains("line 1"));
+    }
+
+    #[test]
+    fn test_parse_section_requires_year() {
+        let err = KnownAnswers::parse("[day01.input]\npart1 = 5").unwrap_err();
+        assert!(err.contains("line 1"));
+    }
+
+    #[test]
+    fn test_years_are_separate() {
+        let known = KnownAnswers::parse("[2024.day01.input]\npart1 = 5").unwrap();
+        assert_eq!(known.get(2024, 1, "input", 1), Some("5"));
+        assert_eq!(known.get(2025, 1, "input", 1), None);
+    }
+
+    #[test]
+    fn test_parse_invalid_key() {
+        let err = KnownAnswers::parse("[2025.day01.input]\nanswer = 5").unwrap_err();
+        assert!(err.contains("line 2"));
+    }
+
+    #[test]
+    fn test_parse_invalid_value() {
+        let err = KnownAnswers::parse("[2025.day01.input]\npart1 = abc").unwrap_err();
+        assert!(err.contains("line 2"));
+    }
+
+    #[test]
+    fn test_strip_comment_keeps_hash_in_string() {
+        assert_eq!(
+            strip_comment(r##"part1 = "#.#" # art"##),
+            r##"part1 = "#.#" "##
+        );
+    }
+
+    #[test]
+    fn test_load_missing_file() {
+        let known = KnownAnswers::load(Path::new("does/not/exist.toml")).unwrap();
+        assert_eq!(known.get(2025, 1, "input", 1), None);
+    }
+
+    #[test]
+    fn test_load_invalid_file() {
+        let path = std::env::temp_dir().join(format!("aoc_answers_{}.toml", std::process::id()));
+        fs::write(&path, "[2025.day01.input]\npart1 = abc").unwrap();
+
+        let err = KnownAnswers::load(&path).unwrap_err();
+
+        assert!(err.starts_with("invalid answers file"));
+        assert!(err.contains("line 2"));
+        let _ = fs::remove_file(&path);
+    }
+
+    #[test]
+    fn test_default_answers_file() {
+        assert_eq!(default_answers_file(), PathBuf::from(ANSWERS_FILE));
+    }
+}
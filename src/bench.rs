@@ -0,0 +1,243 @@
+use std::{
+    fmt,
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use crate::{error::Result, input::Input, solution::Solution};
+
+/// Upper bound of samples per phase so nanosecond phases do not fill the memory
+const MAX_SAMPLES: usize = 100_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BenchConfig {
+    /// Time spent running a phase before samples are recorded
+    pub warmup: Duration,
+    /// Time spent recording samples of a phase
+    pub budget: Duration,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        BenchConfig {
+            warmup: Duration::from_millis(100),
+            budget: Duration::from_millis(500),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Phase {
+    Parse,
+    Part(u8),
+}
+
+impl fmt::Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Phase::Parse => f.pad("parse"),
+            Phase::Part(part) => f.pad(&format!("part {}", part)),
+        }
+    }
+}
+
+/// Summary of the samples recorded for a single phase
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub p95: Duration,
+    pub std_dev: Duration,
+}
+
+impl Stats {
+    /// Computes the statistics of a non-empty list of samples
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        assert!(!samples.is_empty(), "no samples recorded");
+
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let runs = sorted.len();
+        let nanos: Vec<f64> = sorted.iter().map(|d| d.as_nanos() as f64).collect();
+        let mean = nanos.iter().sum::<f64>() / runs as f64;
+        let variance = match runs {
+            1 => 0.0,
+            _ => nanos.iter().map(|n| (n - mean).powi(2)).sum::<f64>() / (runs - 1) as f64,
+        };
+
+        Stats {
+            runs,
+            min: sorted[0],
+            median: percentile(&sorted, 50.0),
+            mean: Duration::from_nanos(mean.round() as u64),
+            p95: percentile(&sorted, 95.0),
+            std_dev: Duration::from_nanos(variance.sqrt().round() as u64),
+        }
+    }
+}
+
+/// Nearest-rank percentile of sorted samples
+fn percentile(sorted: &[Duration], percent: f64) -> Duration {
+    let rank = (percent / 100.0 * sorted.len() as f64).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+/// Benchmark results of every phase of a day
+#[derive(Debug, Clone)]
+pub struct DayBench {
+    pub year: u16,
+    pub day: u8,
+    pub phases: Vec<(Phase, Stats)>,
+}
+
+/// Warms up `f`, then runs it until the budget is used and summarizes the run times.
+///
+/// The returned value is dropped outside of the measured time.
+pub fn measure<T>(config: &BenchConfig, mut f: impl FnMut() -> T) -> Stats {
+    let warmup_start = Instant::now();
+    loop {
+        black_box(f());
+        if warmup_start.elapsed() >= config.warmup {
+            break;
+        }
+    }
+
+    let mut samples = Vec::new();
+    let budget_start = Instant::now();
+    loop {
+        let start = Instant::now();
+        let value = black_box(f());
+        samples.push(start.elapsed());
+        drop(value);
+
+        if budget_start.elapsed() >= config.budget || samples.len() >= MAX_SAMPLES {
+            break;
+        }
+    }
+
+    Stats::from_samples(&samples)
+}
+
+/// Benchmarks parsing and the selected parts of a day separately.
+///
+/// Every phase is run once up front, so an error is reported before any time is measured.
+pub fn bench<S: Solution>(
+    input: &Input,
+    part: Option<u8>,
+    config: &BenchConfig,
+) -> Result<DayBench> {
+    let parsed = S::parse(input).map_err(|err| err.with_day(S::DAY))?;
+    let parts: Vec<u8> = [1, 2]
+        .into_iter()
+        .filter(|&n| part.is_none_or(|p| p == n))
+        .collect();
+    for &number in &parts {
+        match number {
+            1 => S::part1(&parsed),
+            _ => S::part2(&parsed),
+        }
+        .map_err(|err| err.with_day(S::DAY).with_part(number))?;
+    }
+
+    let mut phases = vec![(Phase::Parse, measure(config, || S::parse(black_box(input))))];
+    for number in parts {
+        let stats = match number {
+            1 => measure(config, || S::part1(black_box(&parsed))),
+            _ => measure(config, || S::part2(black_box(&parsed))),
+        };
+        phases.push((Phase::Part(number), stats));
+    }
+
+    Ok(DayBench {
+        year: S::YEAR,
+        day: S::DAY,
+        phases,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn micros(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|&v| Duration::from_micros(v)).collect()
+    }
+
+    #[test]
+    fn test_stats_single_sample() {
+        let stats = Stats::from_samples(&micros(&[7]));
+        assert_eq!(stats.runs, 1);
+        assert_eq!(stats.min, Duration::from_micros(7));
+        assert_eq!(stats.median, Duration::from_micros(7));
+        assert_eq!(stats.mean, Duration::from_micros(7));
+        assert_eq!(stats.p95, Duration::from_micros(7));
+        assert_eq!(stats.std_dev, Duration::ZERO);
+    }
+
+    #[test]
+    fn test_stats_unsorted_samples() {
+        let stats = Stats::from_samples(&micros(&[5, 1, 4, 2, 3]));
+        assert_eq!(stats.runs, 5);
+        assert_eq!(stats.min, Duration::from_micros(1));
+        assert_eq!(stats.median, Duration::from_micros(3));
+        assert_eq!(stats.mean, Duration::from_micros(3));
+        assert_eq!(stats.p95, Duration::from_micros(5));
+    }
+
+    #[test]
+    fn test_stats_std_dev() {
+        // sample variance of 2, 4, 4, 4, 5, 5, 7, 9 is 32 / 7
+        let stats = Stats::from_samples(&micros(&[2, 4, 4, 4, 5, 5, 7, 9]));
+        let expected = (32.0f64 / 7.0).sqrt() * 1000.0;
+        assert!((stats.std_dev.as_nanos() as f64 - expected).abs() < 1.0);
+    }
+
+    #[test]
+    fn test_percentile_nearest_rank() {
+        let samples = micros(&(1..=100).collect::<Vec<_>>());
+        assert_eq!(percentile(&samples, 95.0), Duration::from_micros(95));
+        assert_eq!(percentile(&samples, 50.0), Duration::from_micros(50));
+        assert_eq!(percentile(&samples, 0.0), Duration::from_micros(1));
+        assert_eq!(percentile(&samples, 100.0), Duration::from_micros(100));
+    }
+
+    #[test]
+    #[should_panic(expected = "no samples")]
+    fn test_stats_empty() {
+        Stats::from_samples(&[]);
+    }
+
+    #[test]
+    fn test_measure_runs_at_least_once() {
+        let config = BenchConfig {
+            warmup: Duration::ZERO,
+            budget: Duration::ZERO,
+        };
+        let mut calls = 0;
+        let stats = measure(&config, || calls += 1);
+        assert_eq!(stats.runs, 1);
+        assert_eq!(calls, 2);
+    }
+
+    #[test]
+    fn test_measure_uses_budget() {
+        let config = BenchConfig {
+            warmup: Duration::from_millis(1),
+            budget: Duration::from_millis(5),
+        };
+        let start = Instant::now();
+        let stats = measure(&config, || std::thread::sleep(Duration::from_micros(100)));
+        assert!(start.elapsed() >= Duration::from_millis(6));
+        assert!(stats.runs > 1);
+        assert!(stats.min >= Duration::from_micros(100));
+    }
+
+    #[test]
+    fn test_phase_display() {
+        assert_eq!(Phase::Parse.to_string(), "parse");
+        assert_eq!(format!("{:<7}|", Phase::Part(2)), "part 2 |");
+    }
+}
@@ -0,0 +1,642 @@
+use std::time::Duration;
+
+use advent_of_code::bench::BenchConfig;
+
+pub const USAGE: &str = "\
+Usage: advent_of_code [DAYS] [OPTIONS]
+       advent_of_code new-day <DAY> [--year <YYYY>] [--input-dir <DIR>]
+
+Arguments:
+  [DAYS]               Days to run, e.g. `5`, `1,3,5-8` (default: all days of the year)
+
+Options:
+  -y, --year <YYYY>    Year of the days (default: latest year with solutions)
+  -p, --part <1|2>     Only run the given part
+  -e, --example        Use the example input (inputs/YYYY/dayXX_example.txt)
+  -i, --input <PATH>   Read the input from PATH, `-` reads stdin (single day only)
+      --input-dir <DIR> Directory with the YYYY/dayXX.txt inputs (default: $AOC_INPUT_DIR,
+                       input_dir in aoc.toml or inputs)
+  -r, --repeat <N>     Run every day N times and report the best time
+  -f, --format <FMT>   Output format: table (default), json, jsonl or csv
+  -j, --parallel       Solve the days in parallel on a thread pool
+      --parallel-parts Also solve part 1 and part 2 of a day in parallel
+  -b, --bench          Benchmark parsing and every part with statistics
+      --budget <MS>    Time spent sampling each phase in bench mode (default: 500)
+      --warmup <MS>    Time spent warming up each phase in bench mode (default: 100)
+  -h, --help           Print this help
+
+Commands:
+  new-day <DAY>        Create src/years/yYYYY/dayXX.rs from the day0 template and empty inputs";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Table,
+    Json,
+    /// One JSON record per part and line
+    JsonLines,
+    Csv,
+}
+
+/// Where the puzzle input of a day is read from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputChoice {
+    Real,
+    Example,
+    Path(String),
+    Stdin,
+}
+
+impl InputChoice {
+    /// Name of the input in the answers file, custom files are never verified
+    pub fn answers_key(&self) -> Option<&'static str> {
+        match self {
+            InputChoice::Real => Some("input"),
+            InputChoice::Example => Some("example"),
+            InputChoice::Path(_) | InputChoice::Stdin => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Options {
+    pub year: u16,
+    pub days: Vec<u8>,
+    pub part: Option<u8>,
+    pub input: InputChoice,
+    /// Overrides the configured input directory
+    pub input_dir: Option<String>,
+    pub repeat: usize,
+    pub format: Format,
+    pub bench: Option<BenchConfig>,
+    pub parallel: bool,
+    pub parallel_parts: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    Run(Options),
+    /// Scaffold a new day from the template
+    NewDay {
+        year: u16,
+        day: u8,
+        input_dir: Option<String>,
+    },
+    Help,
+}
+
+/// Parses the command line arguments (without the program name).
+///
+/// `available` are the registered years and days, used to validate the selection.
+pub fn parse_args(args: &[String], available: &[(u16, u8)]) -> Result<Command, String> {
+    if let Some((command, rest)) = args.split_first()
+        && command == "new-day"
+    {
+        return parse_new_day(rest, available);
+    }
+
+    let mut year = None;
+    let mut days: Vec<u8> = Vec::new();
+    let mut part = None;
+    let mut example = false;
+    let mut input_path = None;
+    let mut input_dir = None;
+    let mut repeat = 1;
+    let mut format = Format::Table;
+    let mut bench = false;
+    let mut parallel = false;
+    let mut parallel_parts = false;
+    let mut budget = None;
+    let mut warmup = None;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let (flag, inline_value) = match arg.split_once('=') {
+            Some((flag, value)) if flag.starts_with("--") => (flag, Some(value.to_string())),
+            _ => (arg.as_str(), None),
+        };
+        let mut value = |name: &str| {
+            inline_value
+                .clone()
+                .or_else(|| args.next().cloned())
+                .ok_or(format!("missing value for {}", name))
+        };
+
+        match flag {
+            "-h" | "--help" => return Ok(Command::Help),
+            "-y" | "--year" => year = Some(parse_year(&value("--year")?)?),
+            "-p" | "--part" => {
+                part = match value("--part")?.as_str() {
+                    "1" => Some(1),
+                    "2" => Some(2),
+                    other => return Err(format!("part must be 1 or 2, found {}", other)),
+                }
+            }
+            "-e" | "--example" => example = true,
+            "-i" | "--input" => input_path = Some(value("--input")?),
+            "--input-dir" => input_dir = Some(value("--input-dir")?),
+            "-r" | "--repeat" => {
+                let raw = value("--repeat")?;
+                repeat = match raw.parse::<usize>() {
+                    Ok(n) if n > 0 => n,
+                    _ => return Err(format!("repeat must be a positive number, found {}", raw)),
+                }
+            }
+            "-f" | "--format" => {
+                format = match value("--format")?.as_str() {
+                    "table" => Format::Table,
+                    "json" => Format::Json,
+                    "jsonl" => Format::JsonLines,
+                    "csv" => Format::Csv,
+                    other => {
+                        return Err(format!(
+                            "unknown format {}, expected table, json, jsonl or csv",
+                            other
+                        ));
+                    }
+                }
+            }
+            "-j" | "--parallel" => parallel = true,
+            "--parallel-parts" => {
+                parallel = true;
+                parallel_parts = true;
+            }
+            "-b" | "--bench" => bench = true,
+            "--budget" => budget = Some(parse_millis("--budget", &value("--budget")?)?),
+            "--warmup" => warmup = Some(parse_millis("--warmup", &value("--warmup")?)?),
+            flag if flag.starts_with('-') => {
+                return Err(format!("unknown option {}", flag));
+            }
+            spec => days.extend(parse_day_list(spec)?),
+        }
+    }
+
+    let year = select_year(year, available)?;
+    let available: Vec<u8> = available
+        .iter()
+        .filter(|&&(y, _)| y == year)
+        .map(|&(_, day)| day)
+        .collect();
+    if let Some(&day) = days.iter().find(|day| !available.contains(day)) {
+        return Err(format!(
+            "day {} of {} is not available (available days: {})",
+            day,
+            year,
+            format_day_list(&available)
+        ));
+    }
+    if days.is_empty() {
+        days = available;
+    }
+    days.sort();
+    days.dedup();
+
+    let input = match (example, input_path) {
+        (true, Some(_)) => return Err("--example and --input cannot be combined".to_string()),
+        (_, Some(path)) if days.len() != 1 => {
+            return Err(format!("--input {} requires exactly one day", path));
+        }
+        (_, Some(path)) if path == "-" => InputChoice::Stdin,
+        (_, Some(path)) => InputChoice::Path(path),
+        (true, None) => InputChoice::Example,
+        (false, None) => InputChoice::Real,
+    };
+
+    let bench = match (bench, budget, warmup) {
+        (true, _, _) if repeat > 1 => {
+            return Err("--repeat cannot be combined with --bench".to_string());
+        }
+        (true, _, _) if parallel => {
+            return Err("--parallel cannot be combined with --bench".to_string());
+        }
+        (true, _, _) if matches!(format, Format::JsonLines | Format::Csv) => {
+            return Err("--bench only supports the table and json formats".to_string());
+        }
+        (true, budget, warmup) => {
+            let defaults = BenchConfig::default();
+            Some(BenchConfig {
+                warmup: warmup.unwrap_or(defaults.warmup),
+                budget: budget.unwrap_or(defaults.budget),
+            })
+        }
+        (false, None, None) => None,
+        (false, _, _) => return Err("--budget and --warmup require --bench".to_string()),
+    };
+
+    Ok(Command::Run(Options {
+        year,
+        days,
+        part,
+        input,
+        input_dir,
+        repeat,
+        format,
+        bench,
+        parallel,
+        parallel_parts,
+    }))
+}
+
+/// Parses `<DAY> [--year <YYYY>] [--input-dir <DIR>]`, the year defaults to the latest registered one
+fn parse_new_day(args: &[String], available: &[(u16, u8)]) -> Result<Command, String> {
+    let mut year = None;
+    let mut day = None;
+    let mut input_dir = None;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.split_once('=') {
+            Some(("--year", value)) => year = Some(parse_year(value)?),
+            Some(("--input-dir", value)) => input_dir = Some(value.to_string()),
+            _ => match arg.as_str() {
+                "-h" | "--help" => return Ok(Command::Help),
+                "-y" | "--year" => {
+                    let value = args.next().ok_or("missing value for --year")?;
+                    year = Some(parse_year(value)?);
+                }
+                "--input-dir" => {
+                    let value = args.next().ok_or("missing value for --input-dir")?;
+                    input_dir = Some(value.to_string());
+                }
+                flag if flag.starts_with('-') => return Err(format!("unknown option {}", flag)),
+                _ if day.is_some() => return Err("new-day takes exactly one day".to_string()),
+                value => day = Some(parse_day(value)?),
+            },
+        }
+    }
+
+    let year = match year {
+        Some(year) => year,
+        None => latest_year(available)?,
+    };
+    match day {
+        Some(day @ 1..=25) => Ok(Command::NewDay {
+            year,
+            day,
+            input_dir,
+        }),
+        Some(day) => Err(format!("day must be between 1 and 25, found {}", day)),
+        None => Err("new-day requires a day".to_string()),
+    }
+}
+
+fn parse_year(raw: &str) -> Result<u16, String> {
+    match raw.parse::<u16>() {
+        Ok(year) if (2015..=9999).contains(&year) => Ok(year),
+        _ => Err(format!("year must be 2015 or later, found {}", raw)),
+    }
+}
+
+fn latest_year(available: &[(u16, u8)]) -> Result<u16, String> {
+    available
+        .iter()
+        .map(|&(year, _)| year)
+        .max()
+        .ok_or("no days are registered, pass --year".to_string())
+}
+
+/// The requested year if it has solutions, otherwise the latest year
+fn select_year(year: Option<u16>, available: &[(u16, u8)]) -> Result<u16, String> {
+    let Some(year) = year else {
+        return latest_year(available);
+    };
+    if available.iter().any(|&(y, _)| y == year) {
+        return Ok(year);
+    }
+
+    let mut years: Vec<u16> = available.iter().map(|&(y, _)| y).collect();
+    years.sort();
+    years.dedup();
+    let years: Vec<String> = years.iter().map(u16::to_string).collect();
+    Err(format!(
+        "year {} is not available (available years: {})",
+        year,
+        years.join(", ")
+    ))
+}
+
+fn parse_millis(name: &str, raw: &str) -> Result<Duration, String> {
+    raw.parse()
+        .map(Duration::from_millis)
+        .map_err(|_| format!("{} must be a number of milliseconds, found {}", name, raw))
+}
+
+/// Parses "1,3,5-8" into [1, 3, 5, 6, 7, 8]
+pub fn parse_day_list(spec: &str) -> Result<Vec<u8>, String> {
+    let mut days = Vec::new();
+    for token in spec.split(',').map(str::trim) {
+        if token.is_empty() {
+            return Err(format!("empty day in {}", spec));
+        }
+        match token.split_once('-') {
+            Some((start, end)) => {
+                let start = parse_day(start)?;
+                let end = parse_day(end)?;
+                if start > end {
+                    return Err(format!("day range {} is reversed", token));
+                }
+                days.extend(start..=end);
+            }
+            None => days.push(parse_day(token)?),
+        }
+    }
+    Ok(days)
+}
+
+fn parse_day(token: &str) -> Result<u8, String> {
+    token
+        .trim()
+        .parse()
+        .map_err(|_| format!("day must be a number, found {}", token))
+}
+
+/// Formats days as a compact list, e.g. [1, 2, 3, 5] as "1-3, 5"
+pub fn format_day_list(days: &[u8]) -> String {
+    let mut sorted = days.to_vec();
+    sorted.sort();
+    sorted.dedup();
+
+    let mut groups: Vec<(u8, u8)> = Vec::new();
+    for day in sorted {
+        match groups.last_mut() {
+            Some((_, end)) if *end + 1 == day => *end = day,
+            _ => groups.push((day, day)),
+        }
+    }
+
+    groups
+        .iter()
+        .map(|&(start, end)| match start == end {
+            true => start.to_string(),
+            false => format!("{}-{}", start, end),
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const AVAILABLE: &[(u16, u8)] = &[
+        (2024, 1),
+        (2024, 2),
+        (2025, 1),
+        (2025, 2),
+        (2025, 3),
+        (2025, 4),
+        (2025, 5),
+        (2025, 6),
+        (2025, 7),
+        (2025, 8),
+        (2025, 9),
+        (2025, 10),
+        (2025, 11),
+        (2025, 12),
+    ];
+
+    fn days_of(year: u16) -> Vec<u8> {
+        AVAILABLE
+            .iter()
+            .filter(|&&(y, _)| y == year)
+            .map(|&(_, day)| day)
+            .collect()
+    }
+
+    fn args(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
+
+    fn parse_options(list: &[&str]) -> Options {
+        match parse_args(&args(list), AVAILABLE) {
+            Ok(Command::Run(options)) => options,
+            other => panic!("expected options, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_parse_day_list_single() {
+        assert_eq!(parse_day_list("5"), Ok(vec![5]));
+    }
+
+    #[test]
+    fn test_parse_day_list_mixed() {
+        assert_eq!(parse_day_list("1,3,5-8"), Ok(vec![1, 3, 5, 6, 7, 8]));
+    }
+
+    #[test]
+    fn test_parse_day_list_invalid() {
+        assert!(parse_day_list("a").is_err());
+        assert!(parse_day_list("1,,2").is_err());
+        assert!(parse_day_list("8-5").is_err());
+        assert!(parse_day_list("300").is_err());
+    }
+
+    #[test]
+    fn test_format_day_list() {
+        assert_eq!(format_day_list(&days_of(2025)), "1-12");
+        assert_eq!(format_day_list(&[5, 1, 2, 3, 9]), "1-3, 5, 9");
+        assert_eq!(format_day_list(&[]), "");
+    }
+
+    #[test]
+    fn test_defaults() {
+        let options = parse_options(&[]);
+        assert_eq!(options.year, 2025);
+        assert_eq!(options.days, days_of(2025));
+        assert_eq!(options.part, None);
+        assert_eq!(options.input, InputChoice::Real);
+        assert_eq!(options.repeat, 1);
+        assert_eq!(options.format, Format::Table);
+        assert_eq!(options.bench, None);
+        assert!(!options.parallel);
+        assert!(!options.parallel_parts);
+    }
+
+    #[test]
+    fn test_days_are_sorted_and_deduplicated() {
+        let options = parse_options(&["3,1", "1-2"]);
+        assert_eq!(options.days, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_all_options() {
+        let options = parse_options(&["2", "--part", "2", "-e", "--repeat=5", "--format", "json"]);
+        assert_eq!(options.days, vec![2]);
+        assert_eq!(options.part, Some(2));
+        assert_eq!(options.input, InputChoice::Example);
+        assert_eq!(options.repeat, 5);
+        assert_eq!(options.format, Format::Json);
+    }
+
+    #[test]
+    fn test_record_formats() {
+        assert_eq!(parse_options(&["-f", "jsonl"]).format, Format::JsonLines);
+        assert_eq!(parse_options(&["--format=csv"]).format, Format::Csv);
+    }
+
+    #[test]
+    fn test_input_path() {
+        let options = parse_options(&["4", "--input", "other/day04.txt"]);
+        assert_eq!(
+            options.input,
+            InputChoice::Path("other/day04.txt".to_string())
+        );
+    }
+
+    #[test]
+    fn test_input_path_requires_single_day() {
+        assert!(parse_args(&args(&["1-2", "-i", "x.txt"]), AVAILABLE).is_err());
+        assert!(parse_args(&args(&["-i", "x.txt"]), AVAILABLE).is_err());
+    }
+
+    #[test]
+    fn test_input_stdin() {
+        assert_eq!(parse_options(&["4", "-i", "-"]).input, InputChoice::Stdin);
+        assert!(parse_args(&args(&["-i", "-"]), AVAILABLE).is_err());
+    }
+
+    #[test]
+    fn test_input_dir() {
+        assert_eq!(parse_options(&[]).input_dir, None);
+        let options = parse_options(&["--input-dir", "../puzzles", "-e"]);
+        assert_eq!(options.input_dir, Some("../puzzles".to_string()));
+        assert_eq!(options.input, InputChoice::Example);
+    }
+
+    #[test]
+    fn test_example_and_input_conflict() {
+        assert!(parse_args(&args(&["1", "-e", "-i", "x.txt"]), AVAILABLE).is_err());
+    }
+
+    #[test]
+    fn test_unknown_day_lists_available_days() {
+        let err = parse_args(&args(&["13"]), AVAILABLE).unwrap_err();
+        assert!(err.contains("day 13"));
+        assert!(err.contains("1-12"));
+    }
+
+    #[test]
+    fn test_year() {
+        let options = parse_options(&["--year", "2024"]);
+        assert_eq!(options.year, 2024);
+        assert_eq!(options.days, vec![1, 2]);
+        assert_eq!(parse_options(&["--year=2024", "2"]).days, vec![2]);
+    }
+
+    #[test]
+    fn test_year_invalid() {
+        let err = parse_args(&args(&["-y", "2023"]), AVAILABLE).unwrap_err();
+        assert!(err.contains("2024, 2025"));
+        assert!(parse_args(&args(&["-y", "2024", "3"]), AVAILABLE).is_err());
+        assert!(parse_args(&args(&["-y", "25"]), AVAILABLE).is_err());
+        assert!(parse_args(&args(&[]), &[]).is_err());
+    }
+
+    #[test]
+    fn test_invalid_values() {
+        assert!(parse_args(&args(&["--part", "3"]), AVAILABLE).is_err());
+        assert!(parse_args(&args(&["--part"]), AVAILABLE).is_err());
+        assert!(parse_args(&args(&["--repeat", "0"]), AVAILABLE).is_err());
+        assert!(parse_args(&args(&["--format", "xml"]), AVAILABLE).is_err());
+        assert!(parse_args(&args(&["--verbose"]), AVAILABLE).is_err());
+        assert!(parse_args(&args(&["day1"]), AVAILABLE).is_err());
+    }
+
+    #[test]
+    fn test_bench_defaults() {
+        let options = parse_options(&["--bench"]);
+        assert_eq!(options.bench, Some(BenchConfig::default()));
+    }
+
+    #[test]
+    fn test_bench_budget_and_warmup() {
+        let options = parse_options(&["1", "-b", "--budget", "2000", "--warmup=0"]);
+        assert_eq!(
+            options.bench,
+            Some(BenchConfig {
+                warmup: Duration::ZERO,
+                budget: Duration::from_secs(2),
+            })
+        );
+    }
+
+    #[test]
+    fn test_bench_invalid_combinations() {
+        assert!(parse_args(&args(&["--budget", "10"]), AVAILABLE).is_err());
+        assert!(parse_args(&args(&["-b", "--repeat", "3"]), AVAILABLE).is_err());
+        assert!(parse_args(&args(&["-b", "--budget", "1s"]), AVAILABLE).is_err());
+        assert!(parse_args(&args(&["-b", "-f", "csv"]), AVAILABLE).is_err());
+    }
+
+    #[test]
+    fn test_parallel() {
+        let options = parse_options(&["-j"]);
+        assert!(options.parallel);
+        assert!(!options.parallel_parts);
+    }
+
+    #[test]
+    fn test_parallel_parts_implies_parallel_days() {
+        let options = parse_options(&["--parallel-parts"]);
+        assert!(options.parallel);
+        assert!(options.parallel_parts);
+    }
+
+    #[test]
+    fn test_parallel_and_bench_conflict() {
+        assert!(parse_args(&args(&["-j", "-b"]), AVAILABLE).is_err());
+    }
+
+    #[test]
+    fn test_new_day() {
+        assert_eq!(
+            parse_args(&args(&["new-day", "13"]), AVAILABLE),
+            Ok(Command::NewDay {
+                year: 2025,
+                day: 13,
+                input_dir: None,
+            })
+        );
+        assert_eq!(
+            parse_args(&args(&["new-day", "--year", "2026", "1"]), AVAILABLE),
+            Ok(Command::NewDay {
+                year: 2026,
+                day: 1,
+                input_dir: None,
+            })
+        );
+        assert_eq!(
+            parse_args(
+                &args(&["new-day", "2", "--input-dir", "../puzzles"]),
+                AVAILABLE
+            ),
+            Ok(Command::NewDay {
+                year: 2025,
+                day: 2,
+                input_dir: Some("../puzzles".to_string()),
+            })
+        );
+        assert_eq!(
+            parse_args(&args(&["new-day", "-h"]), AVAILABLE),
+            Ok(Command::Help)
+        );
+    }
+
+    #[test]
+    fn test_new_day_invalid() {
+        assert!(parse_args(&args(&["new-day"]), AVAILABLE).is_err());
+        assert!(parse_args(&args(&["new-day", "0"]), AVAILABLE).is_err());
+        assert!(parse_args(&args(&["new-day", "26"]), AVAILABLE).is_err());
+        assert!(parse_args(&args(&["new-day", "1", "2"]), AVAILABLE).is_err());
+        assert!(parse_args(&args(&["new-day", "1"]), &[]).is_err());
+    }
+
+    #[test]
+    fn test_help() {
+        assert_eq!(parse_args(&args(&["--help"]), AVAILABLE), Ok(Command::Help));
+        assert_eq!(
+            parse_args(&args(&["1", "-h"]), AVAILABLE),
+            Ok(Command::Help)
+        );
+    }
+}
@@ -0,0 +1,160 @@
+use std::{
+    env,
+    ffi::OsString,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    answers::{parse_value, strip_comment},
+    input::default_input_dir,
+};
+
+/// Optional settings file in the working directory
+pub const CONFIG_FILE: &str = "aoc.toml";
+
+/// Environment variable overriding the input directory of the config file
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Settings of the config file, every key is optional.
+///
+/// ```toml
+/// input_dir = "../aoc-inputs"  # relative to the config file
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Config {
+    pub input_dir: Option<PathBuf>,
+}
+
+impl Config {
+    /// Loads the config file, a missing file means the defaults
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let content = match fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(_) => return Ok(Self::default()),
+        };
+        let mut config =
+            Self::parse(&content).map_err(|err| format!("invalid {}: {}", path.display(), err))?;
+        // relative paths point from the file, not from the working directory
+        if let (Some(dir), Some(parent)) = (&config.input_dir, path.parent()) {
+            config.input_dir = Some(parent.join(dir));
+        }
+        Ok(config)
+    }
+
+    pub fn parse(content: &str) -> Result<Self, String> {
+        let mut config = Config::default();
+        for (index, raw_line) in content.lines().enumerate() {
+            let line_number = index + 1;
+            let line = strip_comment(raw_line).trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            let (key, value) = line
+                .split_once('=')
+                .ok_or(format!("line {}: expected key = value", line_number))?;
+            let value = parse_value(value.trim()).ok_or(format!(
+                "line {}: invalid value {}",
+                line_number,
+                value.trim()
+            ))?;
+            match key.trim() {
+                "input_dir" => config.input_dir = Some(PathBuf::from(value)),
+                key => return Err(format!("line {}: unknown key {}", line_number, key)),
+            }
+        }
+        Ok(config)
+    }
+}
+
+/// Directory of the puzzle inputs, from the first of: the `--input-dir` flag,
+/// `AOC_INPUT_DIR`, `input_dir` in `aoc.toml` and the default directory
+pub fn input_dir(flag: Option<&Path>) -> Result<PathBuf, String> {
+    let config = Config::load(Path::new(CONFIG_FILE))?;
+    Ok(resolve_input_dir(flag, env::var_os(INPUT_DIR_VAR), &config))
+}
+
+fn resolve_input_dir(flag: Option<&Path>, var: Option<OsString>, config: &Config) -> PathBuf {
+    if let Some(dir) = flag {
+        return dir.to_path_buf();
+    }
+    if let Some(dir) = var.filter(|dir| !dir.is_empty()) {
+        return PathBuf::from(dir);
+    }
+    config.input_dir.clone().unwrap_or_else(default_input_dir)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(dir: &str) -> Config {
+        Config {
+            input_dir: Some(PathBuf::from(dir)),
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        let config = Config::parse("# inputs\ninput_dir = \"../inputs\" # shared\n").unwrap();
+        assert_eq!(config.input_dir, Some(PathBuf::from("../inputs")));
+        assert_eq!(Config::parse("").unwrap(), Config::default());
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert!(Config::parse("input_dir").unwrap_err().contains("line 1"));
+        assert!(Config::parse("input_dir = ../x").is_err());
+        assert!(
+            Config::parse("\nanswers = \"x\"")
+                .unwrap_err()
+                .contains("line 2")
+        );
+    }
+
+    #[test]
+    fn test_load_relative_to_file() {
+        let dir = env::temp_dir().join(format!("aoc_config_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join(CONFIG_FILE);
+        fs::write(&path, "input_dir = \"puzzles\"").unwrap();
+
+        assert_eq!(
+            Config::load(&path).unwrap().input_dir,
+            Some(dir.join("puzzles"))
+        );
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_load_missing_file() {
+        assert_eq!(
+            Config::load(Path::new("does/not/exist.toml")).unwrap(),
+            Config::default()
+        );
+    }
+
+    #[test]
+    fn test_resolve_precedence() {
+        let flag = Some(Path::new("flag"));
+        let var = || Some(OsString::from("var"));
+
+        assert_eq!(
+            resolve_input_dir(flag, var(), &config("file")),
+            PathBuf::from("flag")
+        );
+        assert_eq!(
+            resolve_input_dir(None, var(), &config("file")),
+            PathBuf::from("var")
+        );
+        assert_eq!(
+            resolve_input_dir(None, Some(OsString::new()), &config("file")),
+            PathBuf::from("file")
+        );
+        assert_eq!(
+            resolve_input_dir(None, None, &Config::default()),
+            default_input_dir()
+        );
+    }
+}
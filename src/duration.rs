@@ -0,0 +1,88 @@
+use std::time::Duration;
+
+/// Width of a formatted duration, large enough for "999.999 ms"
+pub const DURATION_WIDTH: usize = 10;
+
+/// Formats a duration with the largest unit that keeps the value at or above 1,
+/// e.g. "512 ns", "12.345 µs", "3.210 ms" or "2.300 s"
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos();
+    if nanos < 1_000 {
+        return format!("{} ns", nanos);
+    }
+
+    for (unit, scale) in [("µs", 1e3), ("ms", 1e6)] {
+        let value = nanos as f64 / scale;
+        // switch to the next unit if rounding would print "1000.000"
+        if value < 999.9995 {
+            return format!("{:.3} {}", value, unit);
+        }
+    }
+    format!("{:.3} s", duration.as_secs_f64())
+}
+
+/// Share of `part` in `total` in percent, 0 if the total is empty
+pub fn percent_of(part: Duration, total: Duration) -> f64 {
+    if total.is_zero() {
+        return 0.0;
+    }
+    part.as_secs_f64() / total.as_secs_f64() * 100.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_nanoseconds() {
+        assert_eq!(format_duration(Duration::ZERO), "0 ns");
+        assert_eq!(format_duration(Duration::from_nanos(512)), "512 ns");
+        assert_eq!(format_duration(Duration::from_nanos(999)), "999 ns");
+    }
+
+    #[test]
+    fn test_format_microseconds() {
+        assert_eq!(format_duration(Duration::from_nanos(1_000)), "1.000 µs");
+        assert_eq!(format_duration(Duration::from_nanos(12_345)), "12.345 µs");
+    }
+
+    #[test]
+    fn test_format_milliseconds() {
+        assert_eq!(format_duration(Duration::from_micros(3_210)), "3.210 ms");
+        assert_eq!(
+            format_duration(Duration::from_micros(999_999)),
+            "999.999 ms"
+        );
+    }
+
+    #[test]
+    fn test_format_rounds_into_next_unit() {
+        assert_eq!(format_duration(Duration::from_nanos(999_999)), "999.999 µs");
+        assert_eq!(
+            format_duration(Duration::from_nanos(999_999_600)),
+            "1.000 s"
+        );
+    }
+
+    #[test]
+    fn test_format_seconds() {
+        assert_eq!(format_duration(Duration::from_millis(2_300)), "2.300 s");
+        assert_eq!(format_duration(Duration::from_secs(75)), "75.000 s");
+    }
+
+    #[test]
+    fn test_format_fits_width() {
+        for nanos in [0, 999, 999_999, 999_999_999, 99_999_999_999] {
+            let formatted = format_duration(Duration::from_nanos(nanos));
+            assert!(formatted.chars().count() <= DURATION_WIDTH, "{}", formatted);
+        }
+    }
+
+    #[test]
+    fn test_percent_of() {
+        let total = Duration::from_millis(200);
+        assert_eq!(percent_of(Duration::from_millis(50), total), 25.0);
+        assert_eq!(percent_of(total, total), 100.0);
+        assert_eq!(percent_of(Duration::from_millis(1), Duration::ZERO), 0.0);
+    }
+}
@@ -0,0 +1,160 @@
+use std::{fmt, io};
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// What went wrong while loading, parsing or solving an input
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ErrorKind {
+    /// The input file could not be read
+    Io { path: String, message: String },
+    /// The input is malformed, line and column are 1-based
+    Parse {
+        line: usize,
+        column: usize,
+        message: String,
+    },
+    /// The input is well-formed, but the puzzle could not be solved
+    Solve(String),
+}
+
+/// Error of a day run, with the day and part it happened in once known
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Error {
+    pub day: Option<u8>,
+    pub part: Option<u8>,
+    pub kind: ErrorKind,
+}
+
+impl Error {
+    fn new(kind: ErrorKind) -> Self {
+        Error {
+            day: None,
+            part: None,
+            kind,
+        }
+    }
+
+    pub fn io(path: &str, err: &io::Error) -> Self {
+        Self::new(ErrorKind::Io {
+            path: path.to_string(),
+            message: err.to_string(),
+        })
+    }
+
+    /// Malformed input at a 1-based line and column
+    pub fn parse(line: usize, column: usize, message: impl Into<String>) -> Self {
+        Self::new(ErrorKind::Parse {
+            line,
+            column,
+            message: message.into(),
+        })
+    }
+
+    /// Malformed input at `token`, which has to be a slice of the 0-based line `index` in `line`
+    pub fn parse_at(index: usize, line: &str, token: &str, message: impl Into<String>) -> Self {
+        Self::parse(index + 1, column_of(line, token), message)
+    }
+
+    pub fn solve(message: impl Into<String>) -> Self {
+        Self::new(ErrorKind::Solve(message.into()))
+    }
+
+    pub fn with_day(mut self, day: u8) -> Self {
+        self.day = Some(day);
+        self
+    }
+
+    pub fn with_part(mut self, part: u8) -> Self {
+        self.part = Some(part);
+        self
+    }
+}
+
+/// 1-based column of `token` in `line`, 1 if `token` is not a slice of `line`
+pub fn column_of(line: &str, token: &str) -> usize {
+    let offset = (token.as_ptr() as usize).wrapping_sub(line.as_ptr() as usize);
+    match line.get(..offset) {
+        Some(before) if offset + token.len() <= line.len() => before.chars().count() + 1,
+        _ => 1,
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut context = Vec::new();
+        if let Some(day) = self.day {
+            context.push(format!("day {}", day));
+        }
+        if let Some(part) = self.part {
+            context.push(format!("part {}", part));
+        }
+        if let ErrorKind::Parse { line, column, .. } = &self.kind {
+            context.push(format!("line {}", line));
+            context.push(format!("column {}", column));
+        }
+        if !context.is_empty() {
+            write!(f, "{}: ", context.join(", "))?;
+        }
+
+        match &self.kind {
+            ErrorKind::Io { path, message } => write!(f, "cannot read {}: {}", path, message),
+            ErrorKind::Parse { message, .. } | ErrorKind::Solve(message) => f.write_str(message),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display_parse_error() {
+        let err = Error::parse(3, 5, "invalid x coordinate").with_day(12);
+        assert_eq!(
+            err.to_string(),
+            "day 12, line 3, column 5: invalid x coordinate"
+        );
+    }
+
+    #[test]
+    fn test_display_solve_error() {
+        let err = Error::solve("no solution found").with_day(11).with_part(2);
+        assert_eq!(err.to_string(), "day 11, part 2: no solution found");
+        assert_eq!(Error::solve("oops").to_string(), "oops");
+    }
+
+    #[test]
+    fn test_display_io_error() {
+        let io_err = io::Error::new(io::ErrorKind::NotFound, "not found");
+        let err = Error::io("inputs/day07.txt", &io_err).with_day(7);
+        assert_eq!(
+            err.to_string(),
+            "day 7: cannot read inputs/day07.txt: not found"
+        );
+    }
+
+    #[test]
+    fn test_column_of() {
+        let line = "12x5: 1 2";
+        assert_eq!(column_of(line, &line[..2]), 1);
+        assert_eq!(column_of(line, &line[3..4]), 4);
+        assert_eq!(column_of(line, &line[9..]), 10);
+        assert_eq!(column_of(line, &String::from("x")), 1);
+    }
+
+    #[test]
+    fn test_parse_at() {
+        let line = "aaa: bbb zzz";
+        let err = Error::parse_at(1, line, &line[9..], "unknown device");
+        assert_eq!(
+            err.kind,
+            ErrorKind::Parse {
+                line: 2,
+                column: 10,
+                message: "unknown device".to_string()
+            }
+        );
+    }
+}
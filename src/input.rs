@@ -0,0 +1,363 @@
+use std::{
+    collections::HashMap,
+    fmt, fs,
+    io::{self, Read},
+    iter,
+    path::{Path, PathBuf},
+    str::Lines,
+};
+
+use crate::{
+    error::{Error, Result},
+    numbers,
+    types::integer::Integer,
+};
+
+/// Puzzle input read once, with borrowed views into the text instead of a `String` per line.
+///
+/// ```
+/// use advent_of_code::input::Input;
+///
+/// let input = Input::from("3-5\n10-14\n\n1\n5\n");
+/// assert_eq!(input.sections().collect::<Vec<_>>(), vec!["3-5\n10-14", "1\n5"]);
+/// assert_eq!(input.integers::<u32>().collect::<Vec<_>>(), vec![3, 5, 10, 14, 1, 5]);
+/// assert_eq!(input.split("-").next(), Some("3"));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Input {
+    text: String,
+}
+
+impl Input {
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    /// Lines without their line endings
+    pub fn lines(&self) -> Lines<'_> {
+        self.text.lines()
+    }
+
+    /// Blocks of lines separated by one or more blank lines, without the blank lines
+    pub fn sections(&self) -> impl Iterator<Item = &str> {
+        let text = self.text.as_str();
+        let mut offset = 0;
+        let mut lines = text
+            .split_inclusive('\n')
+            .map(move |line| {
+                let start = offset;
+                offset += line.len();
+                (start, line)
+            })
+            .peekable();
+        let is_blank = |line: &str| line.trim().is_empty();
+        let line_end = |start: usize, line: &str| start + line.trim_end_matches(['\n', '\r']).len();
+
+        iter::from_fn(move || {
+            let (start, mut end) = loop {
+                let (start, line) = lines.next()?;
+                if !is_blank(line) {
+                    break (start, line_end(start, line));
+                }
+            };
+            while let Some((next, line)) = lines.next_if(|&(_, line)| !is_blank(line)) {
+                end = line_end(next, line);
+            }
+            Some(&text[start..end])
+        })
+    }
+
+    /// Rows of a character grid as bytes, one per line without the trailing blank lines
+    pub fn grid(&self) -> impl Iterator<Item = &[u8]> {
+        self.text
+            .trim_end_matches(['\n', '\r'])
+            .lines()
+            .map(str::as_bytes)
+    }
+
+    /// All numbers of a text of only numbers, whitespace and commas, see
+    /// [`integers_strict`](numbers::integers_strict); anything else is a parse error
+    pub fn numbers<T: Integer>(&self) -> Result<Vec<T>> {
+        numbers::integers_strict(&self.text)
+    }
+
+    /// Every integer in the text that fits into `T`, see [`numbers`](crate::numbers)
+    pub fn integers<T: Integer>(&self) -> impl Iterator<Item = T> {
+        numbers::integers(&self.text)
+    }
+
+    /// Pieces between `separator`, trimmed and without empty ones
+    pub fn split<'a>(&'a self, separator: &'a str) -> impl Iterator<Item = &'a str> {
+        self.text
+            .trim()
+            .split(separator)
+            .map(str::trim)
+            .filter(|piece| !piece.is_empty())
+    }
+}
+
+impl From<String> for Input {
+    fn from(text: String) -> Self {
+        Input { text }
+    }
+}
+
+impl From<&str> for Input {
+    fn from(text: &str) -> Self {
+        Input {
+            text: text.to_string(),
+        }
+    }
+}
+
+impl AsRef<str> for Input {
+    fn as_ref(&self) -> &str {
+        &self.text
+    }
+}
+
+impl fmt::Display for Input {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.text)
+    }
+}
+
+/// Directory the inputs are read from by default, relative to the working directory
+pub const INPUTS_DIR: &str = "inputs";
+
+/// Where the puzzle inputs are read from.
+///
+/// ```
+/// use advent_of_code::input::InputSource;
+///
+/// let source = InputSource::memory().with_input(2025, 1, false, "L68\nR48");
+/// assert_eq!(source.read(2025, 1, false).unwrap().as_str(), "L68\nR48");
+/// assert!(source.read(2025, 1, true).is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// `YYYY/dayNN.txt` and `YYYY/dayNN_example.txt` below a directory
+    Dir(PathBuf),
+    /// Inputs kept in memory, keyed by year, day and whether it is the example
+    Memory(HashMap<(u16, u8, bool), String>),
+    /// Standard input, whatever day is asked for
+    Stdin,
+    /// A single file, whatever day is asked for
+    Path(PathBuf),
+}
+
+impl Default for InputSource {
+    /// The default input directory, see [`default_input_dir`]
+    fn default() -> Self {
+        InputSource::Dir(default_input_dir())
+    }
+}
+
+impl InputSource {
+    /// An empty in-memory source, filled with [`InputSource::with_input`]
+    pub fn memory() -> Self {
+        InputSource::Memory(HashMap::new())
+    }
+
+    /// Adds an input to an in-memory source, other sources are returned unchanged
+    pub fn with_input(mut self, year: u16, day: u8, example: bool, input: &str) -> Self {
+        if let InputSource::Memory(inputs) = &mut self {
+            inputs.insert((year, day, example), input.to_string());
+        }
+        self
+    }
+
+    /// Reads the real or example input of a day
+    pub fn read(&self, year: u16, day: u8, example: bool) -> Result<Input> {
+        let text = match self {
+            InputSource::Dir(root) => read_path(&root.join(input_file_name(year, day, example)))?,
+            InputSource::Memory(inputs) => match inputs.get(&(year, day, example)) {
+                Some(input) => input.clone(),
+                None => {
+                    return Err(Error::io(
+                        &format!("memory:{}", input_file_name(year, day, example)),
+                        &io::ErrorKind::NotFound.into(),
+                    ));
+                }
+            },
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(|err| Error::io("<stdin>", &err))?;
+                input
+            }
+            InputSource::Path(path) => read_path(path)?,
+        };
+        Ok(Input::from(text))
+    }
+}
+
+/// `inputs`, relative to the working directory
+pub fn default_input_dir() -> PathBuf {
+    PathBuf::from(INPUTS_DIR)
+}
+
+/// `YYYY/dayNN.txt` or `YYYY/dayNN_example.txt`
+pub fn input_file_name(year: u16, day: u8, example: bool) -> String {
+    match example {
+        true => format!("{}/day{:02}_example.txt", year, day),
+        false => format!("{}/day{:02}.txt", year, day),
+    }
+}
+
+fn read_path(path: &Path) -> Result<String> {
+    fs::read_to_string(path).map_err(|err| Error::io(&path.to_string_lossy(), &err))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    fn sections(text: &str) -> Vec<String> {
+        Input::from(text).sections().map(str::to_string).collect()
+    }
+
+    #[test]
+    fn test_lines() {
+        let input = Input::from("a\r\nb\n\nc\n");
+        assert_eq!(input.lines().collect::<Vec<_>>(), vec!["a", "b", "", "c"]);
+    }
+
+    #[test]
+    fn test_sections_basic() {
+        let input = Input::from("line1\nline2\n\nline3\nline4");
+        let groups: Vec<&str> = input.sections().collect();
+        assert_eq!(groups, vec!["line1\nline2", "line3\nline4"]);
+    }
+
+    #[test]
+    fn test_sections_multiple_groups() {
+        let input = Input::from("a\nb\n\nc\n\nd\ne");
+        let groups: Vec<Vec<&str>> = input.sections().map(|s| s.lines().collect()).collect();
+        assert_eq!(groups, vec![vec!["a", "b"], vec!["c"], vec!["d", "e"]]);
+    }
+
+    #[test]
+    fn test_sections_whitespace_and_repeated_blank_lines() {
+        assert_eq!(sections("line1\n   \nline2"), vec!["line1", "line2"]);
+        assert_eq!(sections("\n\na\r\n\r\n\r\nb\r\n\n"), vec!["a", "b"]);
+    }
+
+    #[test]
+    fn test_sections_no_blank_lines() {
+        assert_eq!(sections("line1\nline2\n"), vec!["line1\nline2"]);
+    }
+
+    #[test]
+    fn test_sections_empty() {
+        assert!(sections("").is_empty());
+        assert!(sections("\n\n").is_empty());
+    }
+
+    #[test]
+    fn test_grid() {
+        let input = Input::from("..@\n@.#\r\n\n #.\n\n");
+        let rows: Vec<&[u8]> = input.grid().collect();
+        assert_eq!(rows, vec![b"..@".as_slice(), b"@.#", b"", b" #."]);
+    }
+
+    #[test]
+    fn test_numbers() {
+        let input = Input::from("123\n-456, 7\n");
+        assert_eq!(input.numbers::<i64>().unwrap(), vec![123, -456, 7]);
+        assert_eq!(
+            input.numbers::<u8>().unwrap_err(),
+            Error::parse(2, 1, "unexpected \"-\"")
+        );
+        assert_eq!(
+            Input::from("1\nnot a number").numbers::<i64>().unwrap_err(),
+            Error::parse(2, 1, "unexpected \"n\"")
+        );
+    }
+
+    #[test]
+    fn test_integers() {
+        let input = Input::from("p=0,4 v=3,-3\n");
+        assert_eq!(
+            input.integers::<i32>().collect::<Vec<_>>(),
+            vec![0, 4, 3, -3]
+        );
+    }
+
+    #[test]
+    fn test_split() {
+        let input = Input::from("  apple , banana ,, cherry  \n");
+        assert_eq!(
+            input.split(",").collect::<Vec<_>>(),
+            vec!["apple", "banana", "cherry"]
+        );
+    }
+
+    #[test]
+    fn test_input_file_name() {
+        assert_eq!(input_file_name(2025, 7, false), "2025/day07.txt");
+        assert_eq!(input_file_name(2024, 12, true), "2024/day12_example.txt");
+    }
+
+    #[test]
+    fn test_memory() {
+        let source = InputSource::memory()
+            .with_input(2025, 3, false, "real")
+            .with_input(2025, 3, true, "example");
+
+        assert_eq!(source.read(2025, 3, false).unwrap().as_str(), "real");
+        assert_eq!(source.read(2025, 3, true).unwrap().as_str(), "example");
+    }
+
+    #[test]
+    fn test_memory_missing_input() {
+        let source = InputSource::memory().with_input(2025, 3, false, "real");
+
+        let err = source.read(2024, 3, false).unwrap_err();
+
+        assert!(err.to_string().contains("memory:2024/day03.txt"));
+    }
+
+    #[test]
+    fn test_with_input_keeps_other_sources() {
+        assert_eq!(
+            InputSource::Stdin.with_input(2025, 1, false, "x"),
+            InputSource::Stdin
+        );
+    }
+
+    #[test]
+    fn test_dir() {
+        let root = env::temp_dir().join(format!("aoc_input_dir_{}", std::process::id()));
+        fs::create_dir_all(root.join("2025")).unwrap();
+        fs::write(root.join("2025/day05_example.txt"), "3-5").unwrap();
+        let source = InputSource::Dir(root.clone());
+
+        assert_eq!(source.read(2025, 5, true).unwrap().as_str(), "3-5");
+        let err = source.read(2025, 5, false).unwrap_err();
+        assert!(err.to_string().contains("2025/day05.txt"));
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn test_path_ignores_day() {
+        let path = env::temp_dir().join(format!("aoc_input_path_{}.txt", std::process::id()));
+        fs::write(&path, "custom").unwrap();
+        let source = InputSource::Path(path.clone());
+
+        assert_eq!(source.read(2025, 1, false).unwrap().as_str(), "custom");
+        assert_eq!(source.read(2024, 9, true).unwrap().as_str(), "custom");
+        let _ = fs::remove_file(&path);
+    }
+
+    #[test]
+    fn test_default_dir() {
+        assert_eq!(
+            InputSource::default(),
+            InputSource::Dir(default_input_dir())
+        );
+        assert_eq!(default_input_dir(), PathBuf::from(INPUTS_DIR));
+    }
+}
@@ -0,0 +1,30 @@
+//! Advent of Code toolkit: the shared types and input helpers, the solutions of every year
+//! and the runner that parses, solves, times and verifies them.
+//!
+//! The `advent_of_code` binary is a thin command line interface on top of this crate.
+//!
+//! ```
+//! use advent_of_code::{
+//!     input::Input, solution::Solution, types::answer::Answer, years::y2025::day01::Day01,
+//! };
+//!
+//! let input = Input::from("L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82");
+//! let rotations = Day01::parse(&input).unwrap();
+//! assert_eq!(Day01::part1(&rotations).unwrap(), Answer::Signed(3));
+//! assert_eq!(Day01::part2(&rotations).unwrap(), Answer::Signed(6));
+//! ```
+
+pub mod answers;
+pub mod bench;
+pub mod config;
+pub mod duration;
+pub mod error;
+pub mod input;
+pub mod numbers;
+pub mod report;
+pub mod runner;
+pub mod scan;
+pub mod solution;
+pub mod types;
+pub mod utils;
+pub mod years;
@@ -1,54 +1,249 @@
-mod days;
-mod types;
-mod utils;
-
-use std::{env, time::Instant};
-
-const AVAILABLE_DAYS: &[(u8, fn())] = &[
-    (1, days::day01::solve),
-    (2, days::day02::solve),
-    (3, days::day03::solve),
-    (4, days::day04::solve),
-    (5, days::day05::solve),
-    (6, days::day06::solve),
-    (7, days::day07::solve),
-    (8, days::day08::solve),
-    (9, days::day09::solve),
-    (10, days::day10::solve),
-    (11, days::day11::solve),
-    (12, days::day12::solve),
-];
+mod cli;
+mod scaffold;
+
+use std::{
+    env,
+    path::{Path, PathBuf},
+    process,
+    time::Instant,
+};
+
+use rayon::prelude::*;
+
+use advent_of_code::{
+    answers::{self, KnownAnswers, Verification},
+    bench::BenchConfig,
+    config, error,
+    input::{self, Input, InputSource},
+    report,
+    runner::{self, Bencher, RunOptions, Runner},
+    solution::DayResult,
+    utils,
+};
+use cli::{Command, Format, InputChoice, Options};
 
 fn main() {
-    let args: Vec<String> = env::args().collect();
-    let mut day: u8 = 0;
+    let args: Vec<String> = env::args().skip(1).collect();
+    let available = runner::available_days();
 
-    if args.len() == 2 {
-        day = args[1].parse().expect("Day must be a number");
+    let options = match cli::parse_args(&args, &available) {
+        Ok(Command::Run(options)) => options,
+        Ok(Command::NewDay {
+            year,
+            day,
+            input_dir,
+        }) => {
+            new_day(year, day, input_dir.as_deref());
+            return;
+        }
+        Ok(Command::Help) => {
+            println!("{}", cli::USAGE);
+            return;
+        }
+        Err(err) => {
+            eprintln!("error: {}\n\n{}", err, cli::USAGE);
+            process::exit(2);
+        }
+    };
+
+    let source = match input_source(&options) {
+        Ok(source) => source,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            process::exit(2);
+        }
+    };
+
+    if let Some(config) = options.bench {
+        run_benchmarks(&options, &source, &config);
+        return;
     }
 
-    utils::print_grid_header();
+    let known_answers = match KnownAnswers::load(&or_manifest_dir(answers::default_answers_file()))
+    {
+        Ok(known_answers) => known_answers,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            process::exit(2);
+        }
+    };
 
     let start = Instant::now();
-    match day {
-        0 => solve_all(),
-        i => solve(i),
-    }
+    let results: Vec<DayResult> = match options.parallel {
+        // collecting an indexed parallel iterator keeps the day order
+        true => options
+            .days
+            .par_iter()
+            .map(|&day| solve_day(day, &options, &source, &known_answers))
+            .collect(),
+        false => options
+            .days
+            .iter()
+            .map(|&day| solve_day(day, &options, &source, &known_answers))
+            .collect(),
+    };
     let total_duration = start.elapsed();
-    utils::print_time(total_duration);
+
+    match options.format {
+        Format::Table => utils::print_table(&results, total_duration),
+        Format::Json => println!("{}", report::to_json(&results, total_duration)),
+        Format::JsonLines => print!("{}", report::to_json_lines(&results)),
+        Format::Csv => print!("{}", report::to_csv(&results)),
+    }
+
+    let mut failed = false;
+    for err in results.iter().filter_map(|result| result.error.as_ref()) {
+        eprintln!("error: {}", err);
+        failed = true;
+    }
+    for mismatch in find_mismatches(&results) {
+        eprintln!("{}", mismatch);
+        failed = true;
+    }
+    if failed {
+        process::exit(1);
+    }
 }
 
-fn solve(day: u8) {
-    for &(day_num, solver) in AVAILABLE_DAYS {
-        if day_num == day {
-            solver();
-            return;
+/// Creates the day below the working directory and its inputs in the configured input directory
+fn new_day(year: u16, day: u8, input_dir: Option<&str>) {
+    let created = config::input_dir(input_dir.map(Path::new))
+        .and_then(|inputs| scaffold::create_day(Path::new("."), &inputs, year, day));
+    match created {
+        Ok(created) => {
+            for path in created {
+                println!("created {}", path.display());
+            }
+        }
+        Err(err) => {
+            eprintln!("error: {}", err);
+            process::exit(1);
+        }
+    }
+}
+
+fn find_day(year: u16, day: u8) -> (Runner, Bencher) {
+    runner::find_day(year, day)
+        .unwrap_or_else(|| panic!("Day {} of {} is not registered", day, year))
+}
+
+/// Custom files and stdin are used as they are, puzzle inputs come from the configured directory
+fn input_source(options: &Options) -> Result<InputSource, String> {
+    match &options.input {
+        InputChoice::Path(path) => Ok(InputSource::Path(path.into())),
+        InputChoice::Stdin => Ok(InputSource::Stdin),
+        InputChoice::Real | InputChoice::Example => {
+            let flag = options.input_dir.as_deref().map(Path::new);
+            let dir = config::input_dir(flag)?;
+            match dir == input::default_input_dir() {
+                true => Ok(InputSource::Dir(or_manifest_dir(dir))),
+                false => Ok(InputSource::Dir(dir)),
+            }
+        }
+    }
+}
+
+/// `path` if it exists, otherwise the same path next to the manifest of this crate,
+/// so the default inputs and answers are found when the binary is run from elsewhere
+fn or_manifest_dir(path: PathBuf) -> PathBuf {
+    match path.exists() {
+        true => path,
+        false => Path::new(env!("CARGO_MANIFEST_DIR")).join(path),
+    }
+}
+
+fn read_day_input(source: &InputSource, options: &Options, day: u8) -> error::Result<Input> {
+    let example = options.input == InputChoice::Example;
+    utils::read_input(source, options.year, day, example).map_err(|err| err.with_day(day))
+}
+
+fn run_benchmarks(options: &Options, source: &InputSource, config: &BenchConfig) {
+    if options.format == Format::Table {
+        utils::print_bench_header();
+    }
+
+    let mut benches = Vec::new();
+    let mut failed = false;
+    for &day in &options.days {
+        let (_, bencher) = find_day(options.year, day);
+        let bench = read_day_input(source, options, day)
+            .and_then(|input| bencher(&input, options.part, config));
+        let bench = match bench {
+            Ok(bench) => bench,
+            Err(err) => {
+                eprintln!("error: {}", err);
+                failed = true;
+                continue;
+            }
+        };
+        if options.format == Format::Table {
+            utils::print_bench(&bench);
+        }
+        benches.push(bench);
+    }
+
+    if options.format == Format::Json {
+        println!("{}", report::bench_to_json(&benches));
+    }
+    if failed {
+        process::exit(1);
+    }
+}
+
+/// Solves a day `repeat` times, keeping the best time of every part, and verifies the answers
+fn solve_day(
+    day: u8,
+    options: &Options,
+    source: &InputSource,
+    known_answers: &KnownAnswers,
+) -> DayResult {
+    let (runner, _) = find_day(options.year, day);
+    let input = match read_day_input(source, options, day) {
+        Ok(input) => input,
+        Err(err) => return DayResult::failed(options.year, day, err),
+    };
+
+    let run_options = RunOptions {
+        part: options.part,
+        parallel_parts: options.parallel_parts,
+    };
+    let mut result = runner(&input, &run_options);
+    // a failing day fails the same way again, no need to time it
+    let repeat = match result.error {
+        Some(_) => 1,
+        None => options.repeat,
+    };
+    for _ in 1..repeat {
+        let rerun = runner(&input, &run_options);
+        result.parse_time = result.parse_time.min(rerun.parse_time);
+        for (best, part) in result.parts.iter_mut().zip(rerun.parts) {
+            best.time = best.time.min(part.time);
+        }
+    }
+
+    if let Some(key) = options.input.answers_key() {
+        for part in &mut result.parts {
+            part.verification =
+                known_answers.verify(options.year, day, key, part.part, &part.answer);
         }
     }
+    result
 }
 
-fn solve_all() {
-    AVAILABLE_DAYS.iter().for_each(|&(_, solver)| {
-        solver();
-    });
+fn find_mismatches(results: &[DayResult]) -> Vec<String> {
+    results
+        .iter()
+        .flat_map(|result| {
+            result
+                .parts
+                .iter()
+                .filter_map(move |part| match &part.verification {
+                    Verification::Wrong(expected) => Some(format!(
+                        "Day {} part {}: expected {}, got {}",
+                        result.day, part.part, expected, part.answer
+                    )),
+                    _ => None,
+                })
+        })
+        .collect()
 }
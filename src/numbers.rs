@@ -0,0 +1,208 @@
+//! Integers pulled out of arbitrary text, e.g. `"x=-5, y=12..17"`.
+//!
+//! A number is a run of ASCII digits. For signed types a `-` right in front of the digits
+//! is its sign, unless a letter or digit comes before the `-`: `"3-5"` is 3 and 5, a range,
+//! while `"x=-5"` is -5. Whether `T` is signed is found out by parsing `"-1"` as `T`.
+
+use std::{any::type_name, iter};
+
+use crate::{
+    error::{Error, Result},
+    types::integer::Integer,
+};
+
+/// All integers of `text` that fit into `T`, others are skipped
+///
+/// ```
+/// use advent_of_code::numbers::integers;
+///
+/// let text = "Sensor at x=-2, y=15: beacon 3-5";
+/// assert_eq!(integers::<i64>(text).collect::<Vec<_>>(), vec![-2, 15, 3, 5]);
+/// assert_eq!(integers::<u8>(text).collect::<Vec<_>>(), vec![2, 15, 3, 5]);
+/// ```
+///
+/// `T` is a primitive integer, a float would read `"1.5"` as 1 and 5:
+///
+/// ```compile_fail
+/// let _ = advent_of_code::numbers::integers::<f64>("1.5 -2");
+/// ```
+pub fn integers<T: Integer>(text: &str) -> impl Iterator<Item = T> + '_ {
+    integers_with_offsets(text).map(|(_, number)| number)
+}
+
+/// All integers of `text` that fit into `T` with the byte offset they start at, sign included
+///
+/// ```
+/// use advent_of_code::numbers::integers_with_offsets;
+///
+/// let numbers: Vec<(usize, i32)> = integers_with_offsets("a=-12 b=7").collect();
+/// assert_eq!(numbers, vec![(2, -12), (8, 7)]);
+/// ```
+pub fn integers_with_offsets<T: Integer>(text: &str) -> impl Iterator<Item = (usize, T)> + '_ {
+    tokens(text, is_signed::<T>())
+        .filter_map(|(offset, token)| token.parse().ok().map(|number| (offset, number)))
+}
+
+/// All integers of `text`, which may only be separated by whitespace and commas.
+///
+/// Anything else between the numbers and numbers that do not fit into `T` are
+/// parse errors with their line and column, so are negative numbers for unsigned `T`.
+///
+/// ```
+/// use advent_of_code::numbers::integers_strict;
+///
+/// assert_eq!(integers_strict::<u32>("1, 2\n3").unwrap(), vec![1, 2, 3]);
+/// let err = integers_strict::<u32>("1, 2\n3 x").unwrap_err();
+/// assert_eq!(err.to_string(), "line 2, column 3: unexpected \"x\"");
+/// ```
+pub fn integers_strict<T: Integer>(text: &str) -> Result<Vec<T>> {
+    let mut numbers = Vec::new();
+    let mut end = 0;
+    for (offset, token) in tokens(text, is_signed::<T>()) {
+        check_separators(text, end, offset)?;
+        let number = token.parse().map_err(|_| {
+            let (line, column) = line_column(text, offset);
+            Error::parse(
+                line,
+                column,
+                format!("\"{}\" does not fit into {}", token, type_name::<T>()),
+            )
+        })?;
+        numbers.push(number);
+        end = offset + token.len();
+    }
+    check_separators(text, end, text.len())?;
+    Ok(numbers)
+}
+
+fn is_signed<T: Integer>() -> bool {
+    "-1".parse::<T>().is_ok()
+}
+
+/// Byte offset and text of every number, with its sign if `signed`
+fn tokens(text: &str, signed: bool) -> impl Iterator<Item = (usize, &str)> {
+    let bytes = text.as_bytes();
+    let mut pos = 0;
+    iter::from_fn(move || {
+        while pos < bytes.len() {
+            let start = pos;
+            let is_sign = signed
+                && bytes[start] == b'-'
+                && bytes.get(start + 1).is_some_and(u8::is_ascii_digit)
+                && (start == 0 || !bytes[start - 1].is_ascii_alphanumeric());
+            let digits = if is_sign { start + 1 } else { start };
+            if bytes[digits].is_ascii_digit() {
+                pos = digits;
+                while pos < bytes.len() && bytes[pos].is_ascii_digit() {
+                    pos += 1;
+                }
+                return Some((start, &text[start..pos]));
+            }
+            pos += 1;
+        }
+        None
+    })
+}
+
+/// Error at the first character between `start` and `end` that is no separator
+fn check_separators(text: &str, start: usize, end: usize) -> Result<()> {
+    match text[start..end]
+        .char_indices()
+        .find(|&(_, c)| !c.is_whitespace() && c != ',')
+    {
+        Some((offset, c)) => {
+            let (line, column) = line_column(text, start + offset);
+            Err(Error::parse(line, column, format!("unexpected \"{}\"", c)))
+        }
+        None => Ok(()),
+    }
+}
+
+/// 1-based line and column of the byte `offset`
+fn line_column(text: &str, offset: usize) -> (usize, usize) {
+    let before = &text[..offset];
+    let line_start = before.rfind('\n').map_or(0, |newline| newline + 1);
+    (
+        before.matches('\n').count() + 1,
+        before[line_start..].chars().count() + 1,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn all<T: Integer>(text: &str) -> Vec<T> {
+        integers(text).collect()
+    }
+
+    #[test]
+    fn test_signed() {
+        assert_eq!(all::<i32>("-1 2 -30"), vec![-1, 2, -30]);
+        assert_eq!(all::<i64>("x=-5,y=-10"), vec![-5, -10]);
+    }
+
+    #[test]
+    fn test_dash_after_digit_or_letter_is_no_sign() {
+        assert_eq!(all::<i64>("3-5 10-14"), vec![3, 5, 10, 14]);
+        assert_eq!(all::<i64>("abc-5"), vec![5]);
+        assert_eq!(all::<i64>("- 5 --6"), vec![5, -6]);
+    }
+
+    #[test]
+    fn test_unsigned() {
+        assert_eq!(all::<usize>("-1 2 -30"), vec![1, 2, 30]);
+        assert_eq!(
+            all::<u128>("340282366920938463463374607431768211455"),
+            vec![u128::MAX]
+        );
+    }
+
+    #[test]
+    fn test_skips_numbers_that_do_not_fit() {
+        assert_eq!(all::<u8>("1 256 -3 255"), vec![1, 3, 255]);
+        assert_eq!(all::<i8>("-128 -129 127"), vec![-128, 127]);
+    }
+
+    #[test]
+    fn test_garbage_and_unicode() {
+        assert_eq!(all::<u32>("Game 12: 3 blue, 4 red; ä5ö"), vec![12, 3, 4, 5]);
+        assert!(all::<u32>("no numbers here").is_empty());
+        assert!(all::<u32>("").is_empty());
+    }
+
+    #[test]
+    fn test_offsets() {
+        let numbers: Vec<(usize, i64)> = integers_with_offsets("ä-1 x22").collect();
+        assert_eq!(numbers, vec![(2, -1), (6, 22)]);
+    }
+
+    #[test]
+    fn test_strict() {
+        assert_eq!(
+            integers_strict::<i64>(" 1,-2\n\n3 ,4 ").unwrap(),
+            vec![1, -2, 3, 4]
+        );
+        assert!(integers_strict::<i64>("").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_strict_garbage() {
+        let err = integers_strict::<i64>("1 2\n3 4x").unwrap_err();
+        assert_eq!(err, Error::parse(2, 4, "unexpected \"x\""));
+        let err = integers_strict::<i64>("ä1").unwrap_err();
+        assert_eq!(err, Error::parse(1, 1, "unexpected \"ä\""));
+    }
+
+    #[test]
+    fn test_strict_negative_unsigned() {
+        let err = integers_strict::<u32>("1 -2").unwrap_err();
+        assert_eq!(err, Error::parse(1, 3, "unexpected \"-\""));
+    }
+
+    #[test]
+    fn test_strict_overflow() {
+        let err = integers_strict::<u8>("1\n 300").unwrap_err();
+        assert_eq!(err, Error::parse(2, 2, "\"300\" does not fit into u8"));
+    }
+}
@@ -0,0 +1,408 @@
+use std::time::Duration;
+
+use crate::{
+    bench::{DayBench, Stats},
+    solution::DayResult,
+    types::answer::Answer,
+};
+
+/// Renders all results as a single JSON document
+pub fn to_json(results: &[DayResult], total: Duration) -> String {
+    let days: Vec<String> = results
+        .iter()
+        .map(|result| {
+            let parts: Vec<String> = result
+                .parts
+                .iter()
+                .map(|part| {
+                    format!(
+                        "{{\"part\":{},\"answer\":{},\"status\":\"{}\",\"time_ns\":{}}}",
+                        part.part,
+                        answer_json(&part.answer),
+                        part.verification.label(),
+                        part.time.as_nanos()
+                    )
+                })
+                .collect();
+            format!(
+                "{{\"year\":{},\"day\":{},\"input_hash\":{},\"parse_ns\":{},\"parts\":[{}],\
+                 \"error\":{}}}",
+                result.year,
+                result.day,
+                hash_json(result.input_hash),
+                result.parse_time.as_nanos(),
+                parts.join(","),
+                error_json(result)
+            )
+        })
+        .collect();
+
+    let cpu_time: Duration = results.iter().map(DayResult::cpu_time).sum();
+    format!(
+        "{{\"days\":[{}],\"total_ns\":{},\"cpu_ns\":{}}}",
+        days.join(","),
+        total.as_nanos(),
+        cpu_time.as_nanos()
+    )
+}
+
+/// Renders one JSON record per part and line, a failed day adds a record with status "error"
+pub fn to_json_lines(results: &[DayResult]) -> String {
+    let mut lines = String::new();
+    for result in results {
+        for part in &result.parts {
+            lines.push_str(&format!(
+                "{{\"year\":{},\"day\":{},\"part\":{},\"answer\":{},\"parse_ns\":{},\"solve_ns\":{},\
+                 \"input_hash\":{},\"status\":\"{}\"}}\n",
+                result.year,
+                result.day,
+                part.part,
+                answer_json(&part.answer),
+                result.parse_time.as_nanos(),
+                part.time.as_nanos(),
+                hash_json(result.input_hash),
+                part.verification.label()
+            ));
+        }
+        if let Some(error) = &result.error {
+            lines.push_str(&format!(
+                "{{\"year\":{},\"day\":{},\"part\":{},\"answer\":null,\"parse_ns\":{},\"solve_ns\":null,\
+                 \"input_hash\":{},\"status\":\"error\",\"error\":{}}}\n",
+                result.year,
+                result.day,
+                error
+                    .part
+                    .map_or("null".to_string(), |part| part.to_string()),
+                result.parse_time.as_nanos(),
+                hash_json(result.input_hash),
+                json_string(&error.to_string())
+            ));
+        }
+    }
+    lines
+}
+
+pub const CSV_HEADER: &str = "year,day,part,answer,parse_ns,solve_ns,input_hash,status";
+
+/// Renders a CSV table with a header and one record per part,
+/// a failed day adds a record with status "error" and empty answer and solve time
+pub fn to_csv(results: &[DayResult]) -> String {
+    let mut csv = format!("{}\n", CSV_HEADER);
+    for result in results {
+        for part in &result.parts {
+            let answer = match &part.answer {
+                Answer::NotImplemented => String::new(),
+                answer => csv_field(&answer.to_string()),
+            };
+            csv.push_str(&format!(
+                "{},{},{},{},{},{},{},{}\n",
+                result.year,
+                result.day,
+                part.part,
+                answer,
+                result.parse_time.as_nanos(),
+                part.time.as_nanos(),
+                hash_text(result.input_hash),
+                part.verification.label()
+            ));
+        }
+        if let Some(error) = &result.error {
+            csv.push_str(&format!(
+                "{},{},{},,{},,{},error\n",
+                result.year,
+                result.day,
+                error.part.map_or(String::new(), |part| part.to_string()),
+                result.parse_time.as_nanos(),
+                hash_text(result.input_hash)
+            ));
+        }
+    }
+    csv
+}
+
+/// Input hash as 16 hex digits, empty if the input could not be read
+fn hash_text(hash: Option<u64>) -> String {
+    hash.map_or(String::new(), |hash| format!("{:016x}", hash))
+}
+
+fn hash_json(hash: Option<u64>) -> String {
+    match hash {
+        Some(hash) => format!("\"{:016x}\"", hash),
+        None => "null".to_string(),
+    }
+}
+
+fn error_json(result: &DayResult) -> String {
+    match &result.error {
+        Some(error) => json_string(&error.to_string()),
+        None => "null".to_string(),
+    }
+}
+
+/// Quotes a field if it contains a separator, quote or line break
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Renders benchmark statistics as a single JSON document, all times in nanoseconds
+pub fn bench_to_json(benches: &[DayBench]) -> String {
+    let days: Vec<String> = benches
+        .iter()
+        .map(|bench| {
+            let phases: Vec<String> = bench
+                .phases
+                .iter()
+                .map(|(phase, stats)| {
+                    format!("{{\"phase\":\"{}\",{}}}", phase, stats_json_fields(stats))
+                })
+                .collect();
+            format!(
+                "{{\"year\":{},\"day\":{},\"phases\":[{}]}}",
+                bench.year,
+                bench.day,
+                phases.join(",")
+            )
+        })
+        .collect();
+
+    format!("{{\"days\":[{}]}}", days.join(","))
+}
+
+fn stats_json_fields(stats: &Stats) -> String {
+    format!(
+        "\"runs\":{},\"min_ns\":{},\"median_ns\":{},\"mean_ns\":{},\"p95_ns\":{},\"std_dev_ns\":{}",
+        stats.runs,
+        stats.min.as_nanos(),
+        stats.median.as_nanos(),
+        stats.mean.as_nanos(),
+        stats.p95.as_nanos(),
+        stats.std_dev.as_nanos()
+    )
+}
+
+/// Numbers stay numbers, text becomes a string and missing answers `null`
+pub fn answer_json(answer: &Answer) -> String {
+    match answer {
+        Answer::Signed(_) | Answer::Unsigned(_) | Answer::Wide(_) => answer.to_string(),
+        Answer::Text(text) => json_string(text),
+        Answer::NotImplemented => "null".to_string(),
+    }
+}
+
+pub fn json_string(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len() + 2);
+    escaped.push('"');
+    for ch in text.chars() {
+        match ch {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if (c as u32) < 0x20 => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{answers::Verification, bench::Phase, error::Error, solution::PartResult};
+    use std::io;
+
+    fn part(part: u8, answer: Answer, micros: u64, verification: Verification) -> PartResult {
+        PartResult {
+            part,
+            answer,
+            time: Duration::from_micros(micros),
+            verification,
+        }
+    }
+
+    #[test]
+    fn test_json_string_escaping() {
+        assert_eq!(json_string("abc"), "\"abc\"");
+        assert_eq!(json_string("a\"b\\c"), "\"a\\\"b\\\\c\"");
+        assert_eq!(json_string("#.\n.#"), "\"#.\\n.#\"");
+        assert_eq!(json_string("\u{1}"), "\"\\u0001\"");
+    }
+
+    #[test]
+    fn test_answer_json() {
+        assert_eq!(answer_json(&Answer::Signed(-3)), "-3");
+        assert_eq!(answer_json(&Answer::Wide(u128::MAX)), u128::MAX.to_string());
+        assert_eq!(answer_json(&Answer::from("AB")), "\"AB\"");
+        assert_eq!(answer_json(&Answer::NotImplemented), "null");
+    }
+
+    fn sample_results() -> Vec<DayResult> {
+        vec![
+            DayResult {
+                year: 2025,
+                day: 1,
+                input_hash: Some(0xabc),
+                parse_time: Duration::from_micros(2),
+                parts: vec![
+                    part(1, Answer::Unsigned(3), 4, Verification::Correct),
+                    part(2, Answer::NotImplemented, 0, Verification::Unknown),
+                ],
+                error: None,
+            },
+            DayResult {
+                year: 2024,
+                day: 13,
+                input_hash: Some(u64::MAX),
+                parse_time: Duration::ZERO,
+                parts: vec![part(
+                    1,
+                    Answer::from("A,\"B\""),
+                    1,
+                    Verification::Wrong("C".to_string()),
+                )],
+                error: None,
+            },
+        ]
+    }
+
+    #[test]
+    fn test_to_json() {
+        let results = &sample_results()[..1];
+
+        assert_eq!(
+            to_json(results, Duration::from_micros(10)),
+            "{\"days\":[{\"year\":2025,\"day\":1,\"input_hash\":\"0000000000000abc\",\"parse_ns\":2000,\"parts\":[\
+             {\"part\":1,\"answer\":3,\"status\":\"correct\",\"time_ns\":4000},\
+             {\"part\":2,\"answer\":null,\"status\":\"unknown\",\"time_ns\":0}\
+             ],\"error\":null}],\"total_ns\":10000,\"cpu_ns\":6000}"
+        );
+    }
+
+    #[test]
+    fn test_to_json_lines() {
+        let lines = to_json_lines(&sample_results());
+        let lines: Vec<&str> = lines.lines().collect();
+
+        assert_eq!(
+            lines,
+            vec![
+                "{\"year\":2025,\"day\":1,\"part\":1,\"answer\":3,\"parse_ns\":2000,\"solve_ns\":4000,\
+                 \"input_hash\":\"0000000000000abc\",\"status\":\"correct\"}",
+                "{\"year\":2025,\"day\":1,\"part\":2,\"answer\":null,\"parse_ns\":2000,\"solve_ns\":0,\
+                 \"input_hash\":\"0000000000000abc\",\"status\":\"unknown\"}",
+                "{\"year\":2024,\"day\":13,\"part\":1,\"answer\":\"A,\\\"B\\\"\",\"parse_ns\":0,\"solve_ns\":1000,\
+                 \"input_hash\":\"ffffffffffffffff\",\"status\":\"wrong\"}",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_to_csv() {
+        assert_eq!(
+            to_csv(&sample_results()),
+            "year,day,part,answer,parse_ns,solve_ns,input_hash,status\n\
+             2025,1,1,3,2000,4000,0000000000000abc,correct\n\
+             2025,1,2,,2000,0,0000000000000abc,unknown\n\
+             2024,13,1,\"A,\"\"B\"\"\",0,1000,ffffffffffffffff,wrong\n"
+        );
+    }
+
+    fn failed_results() -> Vec<DayResult> {
+        vec![
+            DayResult::failed(
+                2025,
+                7,
+                Error::io("inputs/2025/day07.txt", &io::ErrorKind::NotFound.into()),
+            ),
+            DayResult {
+                year: 2025,
+                day: 11,
+                input_hash: Some(0x11),
+                parse_time: Duration::from_micros(1),
+                parts: vec![part(1, Answer::Unsigned(5), 2, Verification::Unknown)],
+                error: Some(Error::solve("no path").with_day(11).with_part(2)),
+            },
+        ]
+    }
+
+    #[test]
+    fn test_to_json_failed_day() {
+        assert_eq!(
+            to_json(&failed_results()[..1], Duration::ZERO),
+            "{\"days\":[{\"year\":2025,\"day\":7,\"input_hash\":null,\"parse_ns\":0,\"parts\":[],\
+             \"error\":\"cannot read inputs/2025/day07.txt: entity not found\"}],\
+             \"total_ns\":0,\"cpu_ns\":0}"
+        );
+    }
+
+    #[test]
+    fn test_to_json_lines_failed_part() {
+        let lines = to_json_lines(&failed_results()[1..]);
+        let lines: Vec<&str> = lines.lines().collect();
+
+        assert_eq!(
+            lines[1],
+            "{\"year\":2025,\"day\":11,\"part\":2,\"answer\":null,\"parse_ns\":1000,\"solve_ns\":null,\
+             \"input_hash\":\"0000000000000011\",\"status\":\"error\",\
+             \"error\":\"day 11, part 2: no path\"}"
+        );
+    }
+
+    #[test]
+    fn test_to_csv_failed_days() {
+        assert_eq!(
+            to_csv(&failed_results()),
+            "year,day,part,answer,parse_ns,solve_ns,input_hash,status\n\
+             2025,7,,,0,,,error\n\
+             2025,11,1,5,1000,2000,0000000000000011,unknown\n\
+             2025,11,2,,1000,,0000000000000011,error\n"
+        );
+    }
+
+    #[test]
+    fn test_to_csv_empty() {
+        assert_eq!(to_csv(&[]), format!("{}\n", CSV_HEADER));
+    }
+
+    #[test]
+    fn test_csv_field() {
+        assert_eq!(csv_field("123"), "123");
+        assert_eq!(csv_field("a,b"), "\"a,b\"");
+        assert_eq!(csv_field("#.\n.#"), "\"#.\n.#\"");
+    }
+
+    #[test]
+    fn test_bench_to_json() {
+        let stats = Stats::from_samples(&[Duration::from_nanos(10), Duration::from_nanos(30)]);
+        let benches = vec![DayBench {
+            year: 2025,
+            day: 3,
+            phases: vec![(Phase::Parse, stats), (Phase::Part(1), stats)],
+        }];
+
+        let fields = "\"runs\":2,\"min_ns\":10,\"median_ns\":10,\"mean_ns\":20,\
+                      \"p95_ns\":30,\"std_dev_ns\":14";
+        assert_eq!(
+            bench_to_json(&benches),
+            format!(
+                "{{\"days\":[{{\"year\":2025,\"day\":3,\"phases\":[\
+                 {{\"phase\":\"parse\",{fields}}},{{\"phase\":\"part 1\",{fields}}}\
+                 ]}}]}}"
+            )
+        );
+    }
+
+    #[test]
+    fn test_to_json_empty() {
+        assert_eq!(
+            to_json(&[], Duration::ZERO),
+            "{\"days\":[],\"total_ns\":0,\"cpu_ns\":0}"
+        );
+    }
+}
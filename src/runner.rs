@@ -0,0 +1,122 @@
+use std::time::Instant;
+
+use crate::{
+    answers::Verification,
+    bench::{self, BenchConfig, DayBench},
+    error,
+    input::Input,
+    solution::{DayResult, PartResult, Solution},
+    utils, years,
+};
+
+/// Which parts of a day are solved and how
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RunOptions {
+    /// Only solve the given part, both parts if `None`
+    pub part: Option<u8>,
+    /// Solve part 1 and part 2 in parallel
+    pub parallel_parts: bool,
+}
+
+/// Solves a day for the given input, honoring the part selection and parallelism
+pub type Runner = fn(&Input, &RunOptions) -> DayResult;
+
+/// Benchmarks parsing and the selected parts of a day
+pub type Bencher = fn(&Input, Option<u8>, &BenchConfig) -> error::Result<DayBench>;
+
+// `AVAILABLE_DAYS` with a runner and a bencher for every day module, generated by build.rs
+include!(concat!(env!("OUT_DIR"), "/registry.rs"));
+
+/// Year and day of every registered solution, sorted by year and day
+pub fn available_days() -> Vec<(u16, u8)> {
+    AVAILABLE_DAYS
+        .iter()
+        .map(|&(year, day, _, _)| (year, day))
+        .collect()
+}
+
+/// Runner and bencher of a registered day
+pub fn find_day(year: u16, day: u8) -> Option<(Runner, Bencher)> {
+    AVAILABLE_DAYS
+        .iter()
+        .find(|&&(y, d, _, _)| y == year && d == day)
+        .map(|&(_, _, runner, bencher)| (runner, bencher))
+}
+
+/// Parses the input and solves the selected parts, timing every phase separately.
+///
+/// A failing parse skips the parts, a failing part does not stop the other one.
+///
+/// ```
+/// use advent_of_code::{input::Input, runner::{RunOptions, run}, years::y2025::day01::Day01};
+///
+/// let input = Input::from("L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82");
+/// let result = run::<Day01>(&input, &RunOptions::default());
+/// assert!(result.error.is_none());
+/// assert_eq!(result.parts[0].answer.to_string(), "3");
+/// assert_eq!(result.parts[1].answer.to_string(), "6");
+/// ```
+pub fn run<S: Solution>(input: &Input, options: &RunOptions) -> DayResult {
+    let input_hash = Some(utils::hash_input(input.as_str()));
+
+    let start = Instant::now();
+    let parsed = S::parse(input);
+    let parse_time = start.elapsed();
+
+    let parsed = match parsed {
+        Ok(parsed) => parsed,
+        Err(err) => {
+            return DayResult {
+                input_hash,
+                parse_time,
+                ..DayResult::failed(S::YEAR, S::DAY, err.with_day(S::DAY))
+            };
+        }
+    };
+
+    let solve_part = |number: u8| {
+        let start = Instant::now();
+        let answer = match number {
+            1 => S::part1(&parsed),
+            _ => S::part2(&parsed),
+        };
+        let time = start.elapsed();
+        answer
+            .map(|answer| PartResult {
+                part: number,
+                answer,
+                time,
+                verification: Verification::Unknown,
+            })
+            .map_err(|err| err.with_day(S::DAY).with_part(number))
+    };
+
+    let outcomes = match options.part {
+        Some(number) => vec![solve_part(number)],
+        None if options.parallel_parts => {
+            let (part1, part2) = rayon::join(|| solve_part(1), || solve_part(2));
+            vec![part1, part2]
+        }
+        None => vec![solve_part(1), solve_part(2)],
+    };
+
+    let mut parts = Vec::new();
+    let mut error = None;
+    for outcome in outcomes {
+        match outcome {
+            Ok(part) => parts.push(part),
+            Err(err) => {
+                error.get_or_insert(err);
+            }
+        }
+    }
+
+    DayResult {
+        year: S::YEAR,
+        day: S::DAY,
+        input_hash,
+        parse_time,
+        parts,
+        error,
+    }
+}
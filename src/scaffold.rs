@@ -0,0 +1,194 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// Template every new day starts from, relative to the project root
+pub const TEMPLATE: &str = "src/years/day0.rs";
+
+const TEMPLATE_STRUCT: &str = "Day00";
+
+/// Fills in the year and day constants and the solution name of the template
+pub fn render_template(template: &str, year: u16, day: u8) -> Result<String, String> {
+    let mut found_year = false;
+    let mut found_day = false;
+    let mut rendered: String = template
+        .lines()
+        .map(|line| {
+            if line.starts_with("const YEAR: u16 =") {
+                found_year = true;
+                format!("const YEAR: u16 = {};\n", year)
+            } else if line.starts_with("const DAY: u8 =") {
+                found_day = true;
+                format!("const DAY: u8 = {};\n", day)
+            } else {
+                format!(
+                    "{}\n",
+                    line.replace(TEMPLATE_STRUCT, &format!("Day{:02}", day))
+                )
+            }
+        })
+        .collect();
+    if !found_year || !found_day {
+        return Err(format!(
+            "template {} needs a `const YEAR: u16` and a `const DAY: u8` line",
+            TEMPLATE
+        ));
+    }
+    if !template.ends_with('\n') {
+        rendered.pop();
+    }
+    Ok(rendered)
+}
+
+/// Creates `src/years/yYYYY/dayNN.rs` below `root` from the template and empty
+/// `YYYY/dayNN.txt` and `YYYY/dayNN_example.txt` below `inputs`.
+///
+/// The build script picks up the new module, so nothing else has to be registered.
+/// Existing input files are kept, an existing day is never overwritten.
+pub fn create_day(root: &Path, inputs: &Path, year: u16, day: u8) -> Result<Vec<PathBuf>, String> {
+    let year_dir = root.join(format!("src/years/y{}", year));
+    let source = year_dir.join(format!("day{:02}.rs", day));
+    if source.exists() {
+        return Err(format!("{} already exists", source.display()));
+    }
+
+    let template_path = root.join(TEMPLATE);
+    let template = fs::read_to_string(&template_path)
+        .map_err(|err| format!("cannot read {}: {}", template_path.display(), err))?;
+    let rendered = render_template(&template, year, day)?;
+
+    let inputs_dir = inputs.join(year.to_string());
+    let inputs = [
+        inputs_dir.join(format!("day{:02}.txt", day)),
+        inputs_dir.join(format!("day{:02}_example.txt", day)),
+    ];
+    for dir in [&year_dir, &inputs_dir] {
+        fs::create_dir_all(dir)
+            .map_err(|err| format!("cannot create {}: {}", dir.display(), err))?;
+    }
+
+    let mut created = Vec::new();
+    write_new(&source, &rendered)?;
+    created.push(source);
+    for input in inputs {
+        if !input.exists() {
+            write_new(&input, "")?;
+            created.push(input);
+        }
+    }
+    Ok(created)
+}
+
+/// Writes a file that must not exist yet
+fn write_new(path: &Path, content: &str) -> Result<(), String> {
+    use std::io::Write;
+
+    fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(path)
+        .and_then(|mut file| file.write_all(content.as_bytes()))
+        .map_err(|err| format!("cannot create {}: {}", path.display(), err))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    const TEMPLATE_SOURCE: &str = "use crate::utils;\n\nconst YEAR: u16 = 2025;\nconst DAY: u8 = 1;\n\n\
+                                   pub struct Day00;\n\nimpl Solution for Day00 {}\n";
+
+    // Helper: Project root with only the template in a fresh temporary directory
+    fn temp_project(name: &str) -> PathBuf {
+        let root = env::temp_dir().join(format!("aoc_scaffold_{}_{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("src/years")).unwrap();
+        fs::write(root.join(TEMPLATE), TEMPLATE_SOURCE).unwrap();
+        root
+    }
+
+    #[test]
+    fn test_render_template() {
+        let rendered = render_template(TEMPLATE_SOURCE, 2024, 7).unwrap();
+        assert_eq!(
+            rendered,
+            "use crate::utils;\n\nconst YEAR: u16 = 2024;\nconst DAY: u8 = 7;\n\n\
+             pub struct Day07;\n\nimpl Solution for Day07 {}\n"
+        );
+    }
+
+    #[test]
+    fn test_render_template_without_constants() {
+        assert!(render_template("pub struct Day00;", 2025, 7).is_err());
+        assert!(render_template("const DAY: u8 = 1;", 2025, 7).is_err());
+    }
+
+    #[test]
+    fn test_create_day() {
+        let root = temp_project("create");
+
+        let created = create_day(&root, &root.join("inputs"), 2026, 13).unwrap();
+
+        assert_eq!(created.len(), 3);
+        let source = fs::read_to_string(root.join("src/years/y2026/day13.rs")).unwrap();
+        assert!(source.contains("const YEAR: u16 = 2026;"));
+        assert!(source.contains("const DAY: u8 = 13;"));
+        assert!(source.contains("impl Solution for Day13 "));
+        assert_eq!(
+            fs::read_to_string(root.join("inputs/2026/day13.txt")).unwrap(),
+            ""
+        );
+        assert_eq!(
+            fs::read_to_string(root.join("inputs/2026/day13_example.txt")).unwrap(),
+            ""
+        );
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn test_create_day_keeps_existing_inputs() {
+        let root = temp_project("inputs");
+        fs::create_dir_all(root.join("inputs/2025")).unwrap();
+        fs::write(root.join("inputs/2025/day14.txt"), "puzzle").unwrap();
+
+        let created = create_day(&root, &root.join("inputs"), 2025, 14).unwrap();
+
+        assert_eq!(created.len(), 2);
+        assert_eq!(
+            fs::read_to_string(root.join("inputs/2025/day14.txt")).unwrap(),
+            "puzzle"
+        );
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn test_create_day_with_input_dir() {
+        let root = temp_project("input_dir");
+        let inputs = root.join("puzzles");
+
+        create_day(&root, &inputs, 2025, 16).unwrap();
+
+        assert!(inputs.join("2025/day16.txt").is_file());
+        assert!(inputs.join("2025/day16_example.txt").is_file());
+        assert!(!root.join("inputs").exists());
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn test_create_day_refuses_to_overwrite() {
+        let root = temp_project("overwrite");
+        fs::create_dir_all(root.join("src/years/y2025")).unwrap();
+        fs::write(root.join("src/years/y2025/day15.rs"), "solved").unwrap();
+
+        let err = create_day(&root, &root.join("inputs"), 2025, 15).unwrap_err();
+
+        assert!(err.contains("already exists"));
+        assert_eq!(
+            fs::read_to_string(root.join("src/years/y2025/day15.rs")).unwrap(),
+            "solved"
+        );
+        let _ = fs::remove_dir_all(&root);
+    }
+}
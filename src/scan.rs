@@ -0,0 +1,606 @@
+//! Format-string line parsing behind the [`parse!`](crate::parse) macro.
+//!
+//! A pattern is literal text with fields in braces:
+//!
+//! | Field          | Captures                                                        |
+//! | -------------- | --------------------------------------------------------------- |
+//! | `{}`           | one value, up to the literal that follows the field             |
+//! | `{.N}`         | one value of exactly `N` characters                             |
+//! | `{:SEP}`       | a list of values separated by `SEP`                             |
+//! | `{[SUB]SEP}`   | a list of groups matching the pattern `SUB`, separated by `SEP` |
+//!
+//! `{{` and `}}` are literal braces. Whitespace in the pattern matches any run of
+//! whitespace (including none), a separator of spaces splits on runs of whitespace.
+//! Values are trimmed and converted with [`FromCapture`], which picks the shape of the
+//! result: a tuple per group of fields, a `Vec` per list, `FromStr` types for values.
+
+use std::{any::type_name, fmt, iter, str::FromStr};
+
+use crate::error::{self, Error};
+
+/// Part of the input matched by a field
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Capture<'a> {
+    /// Matched text, a slice of the scanned line
+    pub text: &'a str,
+    pub kind: CaptureKind<'a>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CaptureKind<'a> {
+    Value,
+    /// Items of a `{:SEP}` or `{[SUB]SEP}` field
+    List(Vec<Capture<'a>>),
+    /// Fields of a whole pattern or of one repetition of `SUB`
+    Group(Vec<Capture<'a>>),
+}
+
+/// Conversion that failed at `at`, a slice of the scanned line
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CaptureError<'a> {
+    pub at: &'a str,
+    /// 1-based field of the pattern, `None` for mismatched literals
+    pub field: Option<usize>,
+    pub message: String,
+}
+
+impl<'a> CaptureError<'a> {
+    pub fn new(at: &'a str, message: impl Into<String>) -> Self {
+        CaptureError {
+            at,
+            field: None,
+            message: message.into(),
+        }
+    }
+
+    /// Blames the field `field`; applied from the inside out, so the outermost field wins
+    pub fn in_field(mut self, field: usize) -> Self {
+        self.field = Some(field);
+        self
+    }
+}
+
+/// Types a capture converts into
+pub trait FromCapture<'a>: Sized {
+    fn from_capture(capture: &Capture<'a>) -> Result<Self, CaptureError<'a>>;
+}
+
+/// Line that does not match a pattern, or a field that does not convert
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScanError {
+    /// 1-based field of the pattern, `None` for mismatched literals
+    pub field: Option<usize>,
+    /// 1-based column in the line
+    pub column: usize,
+    pub message: String,
+}
+
+impl ScanError {
+    /// Parse error of the 0-based line `index`
+    pub fn into_error(self, index: usize) -> Error {
+        let message = self.describe();
+        Error::parse(index + 1, self.column, message)
+    }
+
+    fn describe(&self) -> String {
+        match self.field {
+            Some(field) => format!("field {}: {}", field, self.message),
+            None => self.message.clone(),
+        }
+    }
+}
+
+impl fmt::Display for ScanError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "column {}: {}", self.column, self.describe())
+    }
+}
+
+impl std::error::Error for ScanError {}
+
+/// Matches `line` against `pattern` and converts the fields, see the [module docs](self).
+///
+/// Panics if the pattern itself is malformed.
+pub fn scan<'a, T: FromCapture<'a>>(pattern: &str, line: &'a str) -> Result<T, ScanError> {
+    let pieces = parse_pattern(pattern);
+    scan_pieces(&pieces, line, None)
+        .and_then(|fields| {
+            T::from_capture(&Capture {
+                text: line,
+                kind: CaptureKind::Group(fields),
+            })
+        })
+        .map_err(|err| ScanError {
+            field: err.field,
+            column: error::column_of(line, err.at),
+            message: err.message,
+        })
+}
+
+/// Extracts typed fields from a line with a format pattern, see [`scan`](crate::scan).
+///
+/// `parse!(pattern, line)` returns a [`ScanError`](crate::scan::ScanError) with the failing
+/// field and column, `parse!(pattern, line, index)` a parse [`Error`](crate::error::Error)
+/// on the 0-based line `index`.
+///
+/// ```
+/// use advent_of_code::parse;
+///
+/// let (width, height, counts): (usize, usize, Vec<u32>) = parse!("{}x{}: {: }", "12x5: 1 0 2").unwrap();
+/// assert_eq!((width, height, counts), (12, 5, vec![1, 0, 2]));
+///
+/// let (lights, buttons, joltage): (&str, Vec<Vec<usize>>, Vec<u32>) =
+///     parse!("[{}] {[({:,})] } {{{:,}}}", "[.##.] (3) (1,3) {3,5}").unwrap();
+/// assert_eq!((lights, buttons, joltage), (".##.", vec![vec![3], vec![1, 3]], vec![3, 5]));
+///
+/// let err = parse!("{.1}{}", "Lx2", 6).map(|(_, _): (char, i32)| ()).unwrap_err();
+/// assert_eq!(err.to_string(), "line 7, column 2: field 2: cannot parse \"x2\" as i32");
+/// ```
+#[macro_export]
+macro_rules! parse {
+    ($pattern:expr, $line:expr) => {
+        $crate::scan::scan($pattern, $line)
+    };
+    ($pattern:expr, $line:expr, $index:expr) => {
+        $crate::scan::scan($pattern, $line).map_err(|err| err.into_error($index))
+    };
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Piece {
+    Literal(String),
+    Field(Field),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Field {
+    Value,
+    Width(usize),
+    List(String),
+    Group(Vec<Piece>, String),
+}
+
+fn parse_pattern(pattern: &str) -> Vec<Piece> {
+    let mut chars = pattern.chars().peekable();
+    let mut pieces = Vec::new();
+    let mut literal = String::new();
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.next_if_eq(&'{').is_some() => literal.push('{'),
+            '}' if chars.next_if_eq(&'}').is_some() => literal.push('}'),
+            '{' => {
+                if !literal.is_empty() {
+                    pieces.push(Piece::Literal(std::mem::take(&mut literal)));
+                }
+                pieces.push(Piece::Field(parse_field(&mut chars, pattern)));
+            }
+            '}' => panic!("unmatched '}}' in pattern {:?}", pattern),
+            c => literal.push(c),
+        }
+    }
+    if !literal.is_empty() {
+        pieces.push(Piece::Literal(literal));
+    }
+
+    for pair in pieces.windows(2) {
+        if let [Piece::Field(field), Piece::Field(_)] = pair
+            && !matches!(field, Field::Width(_))
+        {
+            panic!(
+                "field without width followed by another field in pattern {:?}",
+                pattern
+            );
+        }
+    }
+    pieces
+}
+
+/// Field spec after its opening brace, up to and including the closing brace
+fn parse_field(chars: &mut impl Iterator<Item = char>, pattern: &str) -> Field {
+    let mut spec = String::new();
+    let mut depth = 0;
+    let mut closed = false;
+    for c in chars.by_ref() {
+        match c {
+            '[' => depth += 1,
+            ']' => depth -= 1,
+            '}' if depth == 0 => {
+                closed = true;
+                break;
+            }
+            _ => {}
+        }
+        spec.push(c);
+    }
+    if !closed {
+        panic!("unclosed '{{' in pattern {:?}", pattern);
+    }
+    let invalid = || -> ! { panic!("invalid field {{{}}} in pattern {:?}", spec, pattern) };
+
+    if spec.is_empty() {
+        Field::Value
+    } else if let Some(width) = spec.strip_prefix('.') {
+        Field::Width(width.parse().unwrap_or_else(|_| invalid()))
+    } else if let Some(separator) = spec.strip_prefix(':') {
+        match separator.is_empty() {
+            true => invalid(),
+            false => Field::List(separator.to_string()),
+        }
+    } else if let Some(group) = spec.strip_prefix('[') {
+        let (sub, separator) = group.rsplit_once(']').unwrap_or_else(|| invalid());
+        match separator.is_empty() {
+            true => invalid(),
+            false => Field::Group(parse_pattern(sub), separator.to_string()),
+        }
+    } else {
+        invalid()
+    }
+}
+
+/// Captures of the fields of `pieces` in `text`, numbered from 1 at the top level,
+/// all errors inside a group blame the group's field `outer`
+fn scan_pieces<'a>(
+    pieces: &[Piece],
+    text: &'a str,
+    outer: Option<usize>,
+) -> Result<Vec<Capture<'a>>, CaptureError<'a>> {
+    let mut captures = Vec::new();
+    let mut pos = 0;
+    for (index, piece) in pieces.iter().enumerate() {
+        let field = match piece {
+            Piece::Literal(literal) => {
+                pos = match_literal(literal, text, pos)
+                    .ok_or_else(|| expected(literal, &text[pos..], outer))?;
+                continue;
+            }
+            Piece::Field(field) => field,
+        };
+        let number = outer.unwrap_or(captures.len() + 1);
+        let end = match (field, pieces.get(index + 1)) {
+            (Field::Width(width), _) => {
+                let rest = &text[pos..];
+                match rest.char_indices().nth(*width) {
+                    Some((end, _)) => pos + end,
+                    None if rest.chars().count() == *width => text.len(),
+                    None => {
+                        return Err(CaptureError::new(
+                            rest,
+                            format!("expected {} characters", width),
+                        )
+                        .in_field(number));
+                    }
+                }
+            }
+            (_, Some(Piece::Literal(literal))) => find_literal(literal, text, pos)
+                .ok_or_else(|| expected(literal, &text[text.len()..], outer))?,
+            _ => text.len(),
+        };
+        captures.push(capture(field, &text[pos..end], number)?);
+        pos = end;
+    }
+
+    let rest = text[pos..].trim();
+    match rest.is_empty() {
+        true => Ok(captures),
+        false => Err(CaptureError {
+            field: outer,
+            ..CaptureError::new(rest, format!("unexpected \"{}\"", rest))
+        }),
+    }
+}
+
+fn expected<'a>(literal: &str, at: &'a str, outer: Option<usize>) -> CaptureError<'a> {
+    CaptureError {
+        field: outer,
+        ..CaptureError::new(at, format!("expected \"{}\"", literal.trim()))
+    }
+}
+
+fn capture<'a>(
+    field: &Field,
+    text: &'a str,
+    number: usize,
+) -> Result<Capture<'a>, CaptureError<'a>> {
+    let text = text.trim();
+    let kind = match field {
+        Field::Value | Field::Width(_) => CaptureKind::Value,
+        Field::List(separator) => CaptureKind::List(
+            split_items(text, separator)
+                .into_iter()
+                .map(|item| Capture {
+                    text: item,
+                    kind: CaptureKind::Value,
+                })
+                .collect(),
+        ),
+        Field::Group(sub, separator) => CaptureKind::List(
+            split_items(text, separator)
+                .into_iter()
+                .map(|item| {
+                    scan_pieces(sub, item, Some(number)).map(|fields| Capture {
+                        text: item,
+                        kind: CaptureKind::Group(fields),
+                    })
+                })
+                .collect::<Result<_, _>>()?,
+        ),
+    };
+    Ok(Capture { text, kind })
+}
+
+/// Trimmed items between separators, a separator of spaces splits on runs of whitespace
+/// and spaces around other separators are optional
+fn split_items<'a>(text: &'a str, separator: &str) -> Vec<&'a str> {
+    let separator = separator.trim();
+    if separator.is_empty() {
+        text.split_whitespace().collect()
+    } else if text.is_empty() {
+        Vec::new()
+    } else {
+        text.split(separator).map(str::trim).collect()
+    }
+}
+
+/// End of `literal` matched at `pos`, whitespace in the literal matches any run of whitespace
+fn match_literal(literal: &str, text: &str, pos: usize) -> Option<usize> {
+    let mut rest = &text[pos..];
+    for c in literal.chars() {
+        rest = match c.is_whitespace() {
+            true => rest.trim_start(),
+            false => rest.strip_prefix(c)?,
+        };
+    }
+    Some(text.len() - rest.len())
+}
+
+/// First position from `pos` where `literal` matches; a literal starting with whitespace
+/// has to start at whitespace so the field before it is not cut short
+fn find_literal(literal: &str, text: &str, pos: usize) -> Option<usize> {
+    let needs_space = literal.starts_with(char::is_whitespace);
+    text[pos..]
+        .char_indices()
+        .map(|(offset, _)| pos + offset)
+        .chain(iter::once(text.len()))
+        .find(|&start| {
+            (!needs_space || text[start..].starts_with(char::is_whitespace) || start == text.len())
+                && match_literal(literal, text, start).is_some()
+        })
+}
+
+fn value<'a, T: FromStr>(capture: &Capture<'a>) -> Result<T, CaptureError<'a>> {
+    match &capture.kind {
+        CaptureKind::Value => capture.text.parse().map_err(|_| {
+            CaptureError::new(
+                capture.text,
+                format!("cannot parse \"{}\" as {}", capture.text, type_name::<T>()),
+            )
+        }),
+        CaptureKind::Group(fields) if fields.len() == 1 => {
+            value(&fields[0]).map_err(|err| err.in_field(1))
+        }
+        CaptureKind::Group(fields) => Err(CaptureError::new(
+            capture.text,
+            format!("pattern has {} fields, expected 1", fields.len()),
+        )),
+        CaptureKind::List(_) => Err(CaptureError::new(
+            capture.text,
+            "expected a single value, found a list",
+        )),
+    }
+}
+
+macro_rules! from_str_capture {
+    ($($ty:ty),*) => {
+        $(
+            impl<'a> FromCapture<'a> for $ty {
+                fn from_capture(capture: &Capture<'a>) -> Result<Self, CaptureError<'a>> {
+                    value(capture)
+                }
+            }
+        )*
+    };
+}
+
+from_str_capture!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64, bool, char, String
+);
+
+impl<'a> FromCapture<'a> for &'a str {
+    fn from_capture(capture: &Capture<'a>) -> Result<Self, CaptureError<'a>> {
+        match &capture.kind {
+            CaptureKind::Value => Ok(capture.text),
+            CaptureKind::Group(fields) if fields.len() == 1 => {
+                Self::from_capture(&fields[0]).map_err(|err| err.in_field(1))
+            }
+            _ => value::<String>(capture).map(|_| capture.text),
+        }
+    }
+}
+
+impl<'a, T: FromCapture<'a>> FromCapture<'a> for Vec<T> {
+    fn from_capture(capture: &Capture<'a>) -> Result<Self, CaptureError<'a>> {
+        match &capture.kind {
+            CaptureKind::List(items) => items.iter().map(T::from_capture).collect(),
+            CaptureKind::Group(fields) if fields.len() == 1 => {
+                Self::from_capture(&fields[0]).map_err(|err| err.in_field(1))
+            }
+            _ => Err(CaptureError::new(capture.text, "expected a list")),
+        }
+    }
+}
+
+macro_rules! tuple_capture {
+    ($len:expr; $($name:ident $index:tt),+) => {
+        impl<'a, $($name: FromCapture<'a>),+> FromCapture<'a> for ($($name,)+) {
+            fn from_capture(capture: &Capture<'a>) -> Result<Self, CaptureError<'a>> {
+                match &capture.kind {
+                    CaptureKind::Group(fields) if fields.len() == $len => Ok(($(
+                        <$name>::from_capture(&fields[$index])
+                            .map_err(|err| err.in_field($index + 1))?,
+                    )+)),
+                    CaptureKind::Group(fields) => Err(CaptureError::new(
+                        capture.text,
+                        format!("pattern has {} fields, expected {}", fields.len(), $len),
+                    )),
+                    _ => Err(CaptureError::new(
+                        capture.text,
+                        format!("expected a group of {} fields", $len),
+                    )),
+                }
+            }
+        }
+    };
+}
+
+tuple_capture!(1; A 0);
+tuple_capture!(2; A 0, B 1);
+tuple_capture!(3; A 0, B 1, C 2);
+tuple_capture!(4; A 0, B 1, C 2, D 3);
+tuple_capture!(5; A 0, B 1, C 2, D 3, E 4);
+tuple_capture!(6; A 0, B 1, C 2, D 3, E 4, F 5);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scan_err<'a, T: FromCapture<'a> + fmt::Debug>(pattern: &str, line: &'a str) -> ScanError {
+        scan::<T>(pattern, line).unwrap_err()
+    }
+
+    #[test]
+    fn test_values() {
+        let (x, y): (i64, u8) = scan("{},{}", "-12,7").unwrap();
+        assert_eq!((x, y), (-12, 7));
+        let name: String = scan("name: {}", "name:   aaa ").unwrap();
+        assert_eq!(name, "aaa");
+    }
+
+    #[test]
+    fn test_width() {
+        let (direction, distance): (char, i32) = scan("{.1}{}", "L68").unwrap();
+        assert_eq!((direction, distance), ('L', 68));
+        let (a, b, c): (u8, u8, &str) = scan("{.2}{.1}{}", "1234").unwrap();
+        assert_eq!((a, b, c), (12, 3, "4"));
+    }
+
+    #[test]
+    fn test_lists() {
+        let (w, h, counts): (usize, usize, Vec<usize>) =
+            scan("{}x{}: {: }", "4x4:  0 2\t3").unwrap();
+        assert_eq!((w, h, counts), (4, 4, vec![0, 2, 3]));
+        let numbers: Vec<u32> = scan("<{:, }>", "<1, 2,3>").unwrap();
+        assert_eq!(numbers, vec![1, 2, 3]);
+        let empty: Vec<u32> = scan("<{:,}>", "<>").unwrap();
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_repeated_groups() {
+        let pairs: Vec<(i32, i32)> = scan("{[{}..{}]; }", "1..2; 3..-4").unwrap();
+        assert_eq!(pairs, vec![(1, 2), (3, -4)]);
+
+        let (lights, buttons, joltage): (&str, Vec<Vec<usize>>, Vec<u32>) = scan(
+            "[{}] {[({:,})] } {{{:,}}}",
+            "[.##.] (3) (1,3) (2) {3,5,4,7}",
+        )
+        .unwrap();
+        assert_eq!(lights, ".##.");
+        assert_eq!(buttons, vec![vec![3], vec![1, 3], vec![2]]);
+        assert_eq!(joltage, vec![3, 5, 4, 7]);
+    }
+
+    #[test]
+    fn test_invalid_field() {
+        let err = scan_err::<(usize, usize)>("{}x{}", "12xab");
+        assert_eq!(err.field, Some(2));
+        assert_eq!(err.column, 4);
+        assert_eq!(err.message, "cannot parse \"ab\" as usize");
+        assert_eq!(
+            err.to_string(),
+            "column 4: field 2: cannot parse \"ab\" as usize"
+        );
+    }
+
+    #[test]
+    fn test_invalid_item_blames_outer_field() {
+        let err = scan_err::<(&str, Vec<Vec<u8>>)>("{} {[({:,})] }", "x (1,2) (3,y)");
+        assert_eq!(err.field, Some(2));
+        assert_eq!(err.column, 12);
+
+        let err = scan_err::<(&str, Vec<Vec<u8>>)>("{} {[({:,})] }", "x (1,2) (3");
+        assert_eq!(err.field, Some(2));
+        assert_eq!(err.message, "expected \")\"");
+    }
+
+    #[test]
+    fn test_missing_literal() {
+        let err = scan_err::<(u8, u8)>("{}x{}", "12");
+        assert_eq!(err.field, None);
+        assert_eq!(err.column, 3);
+        assert_eq!(err.message, "expected \"x\"");
+
+        let err = scan_err::<u8>("[{}]", "(1]");
+        assert_eq!((err.column, err.message.as_str()), (1, "expected \"[\""));
+    }
+
+    #[test]
+    fn test_trailing_input() {
+        let err = scan_err::<(u8, u8)>("{}-{}:", "1-2: 3");
+        assert_eq!((err.column, err.message.as_str()), (6, "unexpected \"3\""));
+    }
+
+    #[test]
+    fn test_short_width() {
+        let err = scan_err::<(u8, &str)>("{.3}{}", "12");
+        assert_eq!(err.field, Some(1));
+        assert_eq!(err.message, "expected 3 characters");
+    }
+
+    #[test]
+    fn test_arity_mismatch() {
+        let err = scan_err::<(u8, u8)>("{}", "1");
+        assert_eq!(err.message, "pattern has 1 fields, expected 2");
+        let err = scan_err::<u8>("{:,}", "1,2");
+        assert_eq!(err.message, "expected a single value, found a list");
+    }
+
+    #[test]
+    fn test_escaped_braces() {
+        let value: u8 = scan("{{{}}}", "{7}").unwrap();
+        assert_eq!(value, 7);
+    }
+
+    #[test]
+    fn test_into_error() {
+        let err = scan_err::<(u8, u8)>("{}x{}", "1xz").into_error(4);
+        assert_eq!(err, Error::parse(5, 3, "field 2: cannot parse \"z\" as u8"));
+    }
+
+    #[test]
+    fn test_macro() {
+        let (a, b): (u8, u8) = crate::parse!("{}/{}", "3/4").unwrap();
+        assert_eq!((a, b), (3, 4));
+        let err = crate::parse!("{}/{}", "3/x", 0)
+            .map(|(_, _): (u8, u8)| ())
+            .unwrap_err();
+        assert_eq!(err, Error::parse(1, 3, "field 2: cannot parse \"x\" as u8"));
+    }
+
+    #[test]
+    #[should_panic(expected = "field without width followed by another field")]
+    fn test_adjacent_fields_panic() {
+        let _: Result<(u8, u8), _> = scan("{}{}", "12");
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid field {:} in pattern")]
+    fn test_invalid_spec_panic() {
+        let _: Result<Vec<u8>, _> = scan("{:}", "12");
+    }
+
+    #[test]
+    #[should_panic(expected = "unclosed '{' in pattern \"{}x{\"")]
+    fn test_unclosed_field_panic() {
+        let _: Result<(u8, u8), _> = scan("{}x{", "1x2");
+    }
+}
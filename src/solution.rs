@@ -0,0 +1,77 @@
+use std::time::Duration;
+
+use crate::{
+    answers::Verification,
+    error::{Error, Result},
+    input::Input,
+    types::answer::Answer,
+};
+
+/// A solver for a single day of the puzzle.
+///
+/// The runner takes care of loading the input, timing each phase and
+/// reporting the results, so implementors only have to transform the input.
+/// Malformed input is reported as an [`Error`] instead of a panic.
+pub trait Solution {
+    /// Year of the puzzle, used together with the day to locate the input file
+    const YEAR: u16;
+
+    /// Day of the puzzle, used to locate the input file
+    const DAY: u8;
+
+    /// Parsed representation of the input, shared by both parts (possibly across threads)
+    type Parsed: Sync;
+
+    /// Converts the raw puzzle input into the parsed representation
+    fn parse(input: &Input) -> Result<Self::Parsed>;
+
+    /// Solves part 1 of the puzzle
+    fn part1(input: &Self::Parsed) -> Result<Answer>;
+
+    /// Solves part 2 of the puzzle, if the day has one
+    fn part2(_input: &Self::Parsed) -> Result<Answer> {
+        Ok(Answer::NotImplemented)
+    }
+}
+
+/// Answer and timing of a single part
+#[derive(Debug, Clone)]
+pub struct PartResult {
+    pub part: u8,
+    pub answer: Answer,
+    pub time: Duration,
+    pub verification: Verification,
+}
+
+/// Answers and timings of a single day run
+#[derive(Debug, Clone)]
+pub struct DayResult {
+    pub year: u16,
+    pub day: u8,
+    /// FNV-1a hash of the raw input, identifies which input was solved
+    pub input_hash: Option<u64>,
+    pub parse_time: Duration,
+    /// Parts solved successfully
+    pub parts: Vec<PartResult>,
+    /// Error of the parse phase or of the first failing part, failed parts are not in `parts`
+    pub error: Option<Error>,
+}
+
+impl DayResult {
+    /// Result of a day that failed before its input could be parsed
+    pub fn failed(year: u16, day: u8, error: Error) -> Self {
+        DayResult {
+            year,
+            day,
+            input_hash: None,
+            parse_time: Duration::ZERO,
+            parts: Vec::new(),
+            error: Some(error),
+        }
+    }
+
+    /// Time spent parsing and solving, summed over all phases
+    pub fn cpu_time(&self) -> Duration {
+        self.parse_time + self.parts.iter().map(|part| part.time).sum::<Duration>()
+    }
+}
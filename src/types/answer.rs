@@ -0,0 +1,139 @@
+use std::fmt;
+
+/// Answer of a single puzzle part.
+///
+/// Keeps the value in its natural type so negative, very large or textual
+/// answers can be reported without being squeezed into a `usize`.
+///
+/// ```
+/// use advent_of_code::types::answer::Answer;
+///
+/// assert_eq!(Answer::from(-4i32), Answer::Signed(-4));
+/// assert_eq!(Answer::from(u128::MAX).to_string(), u128::MAX.to_string());
+/// assert_eq!(Answer::from("AB").to_string(), "AB");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Signed(i64),
+    Unsigned(u64),
+    Wide(u128),
+    Text(String),
+    NotImplemented,
+}
+
+impl Answer {
+    pub fn is_implemented(&self) -> bool {
+        !matches!(self, Answer::NotImplemented)
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Signed(n) => fmt::Display::fmt(n, f),
+            Answer::Unsigned(n) => fmt::Display::fmt(n, f),
+            Answer::Wide(n) => fmt::Display::fmt(n, f),
+            Answer::Text(s) => f.pad(s),
+            Answer::NotImplemented => f.pad("not implemented"),
+        }
+    }
+}
+
+macro_rules! impl_from_signed {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(n: $t) -> Self {
+                Answer::Signed(n as i64)
+            }
+        })*
+    };
+}
+
+macro_rules! impl_from_unsigned {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(n: $t) -> Self {
+                Answer::Unsigned(n as u64)
+            }
+        })*
+    };
+}
+
+impl_from_signed!(i8, i16, i32, i64, isize);
+impl_from_unsigned!(u8, u16, u32, u64, usize);
+
+impl From<u128> for Answer {
+    fn from(n: u128) -> Self {
+        Answer::Wide(n)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Text(s.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_signed() {
+        assert_eq!(Answer::from(-5i32), Answer::Signed(-5));
+        assert_eq!(Answer::from(1071i64), Answer::Signed(1071));
+    }
+
+    #[test]
+    fn test_from_unsigned() {
+        assert_eq!(Answer::from(42usize), Answer::Unsigned(42));
+        assert_eq!(Answer::from(u64::MAX), Answer::Unsigned(u64::MAX));
+    }
+
+    #[test]
+    fn test_from_u128() {
+        let big = u64::MAX as u128 + 1;
+        assert_eq!(Answer::from(big), Answer::Wide(big));
+    }
+
+    #[test]
+    fn test_from_text() {
+        assert_eq!(Answer::from("EFJKZ"), Answer::Text("EFJKZ".to_string()));
+        assert_eq!(
+            Answer::from("EFJKZ".to_string()),
+            Answer::Text("EFJKZ".to_string())
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Answer::Signed(-12).to_string(), "-12");
+        assert_eq!(Answer::Unsigned(18595663903).to_string(), "18595663903");
+        assert_eq!(
+            Answer::Wide(u128::MAX).to_string(),
+            "340282366920938463463374607431768211455"
+        );
+        assert_eq!(Answer::Text("abc".to_string()).to_string(), "abc");
+        assert_eq!(Answer::NotImplemented.to_string(), "not implemented");
+    }
+
+    #[test]
+    fn test_display_padding() {
+        assert_eq!(format!("{:<6}|", Answer::Signed(-1)), "-1    |");
+        assert_eq!(format!("{:<6}|", Answer::Unsigned(7)), "7     |");
+        assert_eq!(format!("{:<6}|", Answer::Text("ab".to_string())), "ab    |");
+        assert_eq!(format!("{:>6}|", Answer::Wide(12)), "    12|");
+    }
+
+    #[test]
+    fn test_is_implemented() {
+        assert!(Answer::Unsigned(0).is_implemented());
+        assert!(!Answer::NotImplemented.is_implemented());
+    }
+}
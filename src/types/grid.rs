@@ -0,0 +1,562 @@
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+use crate::{
+    error::{Error, Result},
+    input::Input,
+    types::point3d::Point2D,
+};
+
+/// Offsets of the orthogonal neighbours: up, left, right, down
+const NEIGHBOURS_4: [(isize, isize); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+
+/// Offsets of all neighbours including the diagonals, row by row
+const NEIGHBOURS_8: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/// Rectangular grid stored row-major in a single `Vec`.
+///
+/// Cells are addressed by [`Point2D`] with `x` as the column and `y` as the row,
+/// points outside of the grid are `None` for [`Grid::get`] and never returned as neighbours.
+///
+/// ```
+/// use advent_of_code::{input::Input, types::{grid::Grid, point3d::Point2D}};
+///
+/// let grid = Grid::parse(&Input::from("..@\n@@.\n"), |_, cell| Some(cell == b'@')).unwrap();
+/// assert_eq!((grid.width(), grid.height()), (3, 2));
+/// assert_eq!(grid.get(Point2D::new(2, 0)), Some(&true));
+/// assert_eq!(grid.get(Point2D::new(-1, 0)), None);
+/// assert_eq!(grid.neighbours8(Point2D::new(1, 1)).filter(|&p| grid[p]).count(), 2);
+/// assert_eq!(grid.map(|&roll| if roll { '@' } else { '.' }).to_string(), "..@\n@@.");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Grid of `width` columns from row-major cells, `None` if the cells do not fill whole rows
+    pub fn from_vec(width: usize, cells: Vec<T>) -> Option<Self> {
+        match width {
+            0 if cells.is_empty() => Some(Grid::with_rows(0, 0, cells)),
+            0 => None,
+            _ if !cells.len().is_multiple_of(width) => None,
+            _ => Some(Grid::with_rows(width, cells.len() / width, cells)),
+        }
+    }
+
+    /// Grid from rows of equal length, the error is the index of the first row of another length.
+    ///
+    /// No rows or only empty rows give the empty grid of 0 by 0 cells.
+    pub fn from_rows<R: IntoIterator<Item = T>>(
+        rows: impl IntoIterator<Item = R>,
+    ) -> std::result::Result<Self, usize> {
+        let mut cells = Vec::new();
+        let mut width = None;
+        let mut height = 0;
+        for (index, row) in rows.into_iter().enumerate() {
+            let start = cells.len();
+            cells.extend(row);
+            let len = cells.len() - start;
+            if *width.get_or_insert(len) != len {
+                return Err(index);
+            }
+            height += 1;
+        }
+        Ok(Grid::with_rows(width.unwrap_or(0), height, cells))
+    }
+
+    /// Grid of `width` by `height` cells, all set to `value`
+    pub fn filled(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid::with_rows(width, height, vec![value; width * height])
+    }
+
+    /// A grid without columns or without rows has neither, so that every empty grid is 0 by 0
+    fn with_rows(width: usize, height: usize, cells: Vec<T>) -> Self {
+        match width == 0 || height == 0 {
+            true => Grid {
+                width: 0,
+                height: 0,
+                cells,
+            },
+            false => Grid {
+                width,
+                height,
+                cells,
+            },
+        }
+    }
+
+    /// Parses one cell per byte of every line of the input.
+    ///
+    /// `cell` returns `None` for bytes that are not a valid cell at that point, which is
+    /// reported as an unknown cell; lines of another width than the first one are reported too.
+    pub fn parse(input: &Input, mut cell: impl FnMut(Point2D, u8) -> Option<T>) -> Result<Self> {
+        let mut cells = Vec::new();
+        let mut width = None;
+        let mut height = 0;
+        for (y, row) in input.grid().enumerate() {
+            let width = *width.get_or_insert(row.len());
+            if row.len() != width {
+                return Err(Error::parse(
+                    y + 1,
+                    row.len().min(width) + 1,
+                    format!("expected {} cells like the first line", width),
+                ));
+            }
+            for (x, &byte) in row.iter().enumerate() {
+                let point = Point2D::new(x as isize, y as isize);
+                let value = cell(point, byte).ok_or_else(|| {
+                    Error::parse(y + 1, x + 1, format!("unknown cell '{}'", byte as char))
+                })?;
+                cells.push(value);
+            }
+            height += 1;
+        }
+        Ok(Grid::with_rows(width.unwrap_or(0), height, cells))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, point: Point2D) -> bool {
+        point.x >= 0
+            && point.y >= 0
+            && (point.x as usize) < self.width
+            && (point.y as usize) < self.height
+    }
+
+    pub fn get(&self, point: Point2D) -> Option<&T> {
+        self.index_of(point).map(|index| &self.cells[index])
+    }
+
+    pub fn get_mut(&mut self, point: Point2D) -> Option<&mut T> {
+        self.index_of(point).map(|index| &mut self.cells[index])
+    }
+
+    fn index_of(&self, point: Point2D) -> Option<usize> {
+        self.contains(point)
+            .then(|| point.y as usize * self.width + point.x as usize)
+    }
+
+    fn point_of(&self, index: usize) -> Point2D {
+        Point2D::new((index % self.width) as isize, (index / self.width) as isize)
+    }
+
+    /// All points, row by row
+    pub fn points(&self) -> impl Iterator<Item = Point2D> + '_ {
+        (0..self.cells.len()).map(|index| self.point_of(index))
+    }
+
+    /// All cells with their points, row by row
+    pub fn iter(&self) -> impl Iterator<Item = (Point2D, &T)> {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(index, cell)| (self.point_of(index), cell))
+    }
+
+    /// Orthogonal neighbours of `point` inside the grid
+    pub fn neighbours4(&self, point: Point2D) -> impl Iterator<Item = Point2D> + '_ {
+        self.neighbours(point, &NEIGHBOURS_4)
+    }
+
+    /// Orthogonal and diagonal neighbours of `point` inside the grid
+    pub fn neighbours8(&self, point: Point2D) -> impl Iterator<Item = Point2D> + '_ {
+        self.neighbours(point, &NEIGHBOURS_8)
+    }
+
+    fn neighbours(
+        &self,
+        point: Point2D,
+        offsets: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = Point2D> + '_ {
+        offsets
+            .iter()
+            .map(move |&(dx, dy)| Point2D::new(point.x + dx, point.y + dy))
+            .filter(|&neighbour| self.contains(neighbour))
+    }
+
+    /// Cells of row `y`, panics if the row is outside of the grid
+    pub fn row(&self, y: usize) -> &[T] {
+        assert!(y < self.height, "row {} outside of {} rows", y, self.height);
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> {
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    /// Cells of column `x` from top to bottom, panics if the column is outside of the grid
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(
+            x < self.width,
+            "column {} outside of {} columns",
+            x,
+            self.width
+        );
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// First point, row by row, whose cell matches `predicate`
+    pub fn position(&self, predicate: impl FnMut(&T) -> bool) -> Option<Point2D> {
+        self.cells
+            .iter()
+            .position(predicate)
+            .map(|index| self.point_of(index))
+    }
+
+    /// All points, row by row, whose cells match `predicate`
+    pub fn positions<'a>(
+        &'a self,
+        mut predicate: impl FnMut(&T) -> bool + 'a,
+    ) -> impl Iterator<Item = Point2D> + 'a {
+        self.iter()
+            .filter(move |(_, cell)| predicate(cell))
+            .map(|(point, _)| point)
+    }
+
+    /// First point, row by row, holding `value`
+    pub fn find(&self, value: &T) -> Option<Point2D>
+    where
+        T: PartialEq,
+    {
+        self.position(|cell| cell == value)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Rows become columns
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.rebuild(self.height, self.width, |x, y| (y, x))
+    }
+
+    /// Rotates a quarter turn clockwise, the first column becomes the first row
+    pub fn rotate_clockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.rebuild(self.height, self.width, |x, y| (y, self.height - 1 - x))
+    }
+
+    /// Rotates a quarter turn counterclockwise, the last column becomes the first row
+    pub fn rotate_counterclockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.rebuild(self.height, self.width, |x, y| (self.width - 1 - y, x))
+    }
+
+    /// Mirrors left and right
+    pub fn flip_horizontal(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.rebuild(self.width, self.height, |x, y| (self.width - 1 - x, y))
+    }
+
+    /// Mirrors top and bottom
+    pub fn flip_vertical(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.rebuild(self.width, self.height, |x, y| (x, self.height - 1 - y))
+    }
+
+    /// Grid of the new size whose cell at (x, y) is the cell of this grid at `source(x, y)`
+    fn rebuild(
+        &self,
+        width: usize,
+        height: usize,
+        source: impl Fn(usize, usize) -> (usize, usize),
+    ) -> Self
+    where
+        T: Clone,
+    {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|(x, y)| {
+                let (sx, sy) = source(x, y);
+                self.cells[sy * self.width + sx].clone()
+            })
+            .collect();
+        Grid::with_rows(width, height, cells)
+    }
+}
+
+impl<T> Index<Point2D> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point2D) -> &T {
+        match self.index_of(point) {
+            Some(index) => &self.cells[index],
+            None => panic!(
+                "point ({}, {}) outside of the {}x{} grid",
+                point.x, point.y, self.width, self.height
+            ),
+        }
+    }
+}
+
+impl<T> IndexMut<Point2D> for Grid<T> {
+    fn index_mut(&mut self, point: Point2D) -> &mut T {
+        match self.index_of(point) {
+            Some(index) => &mut self.cells[index],
+            None => panic!(
+                "point ({}, {}) outside of the {}x{} grid",
+                point.x, point.y, self.width, self.height
+            ),
+        }
+    }
+}
+
+/// Renders one line per row without separators between the cells
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Helper: 3x2 grid of the characters
+    //   abc
+    //   def
+    fn letters() -> Grid<char> {
+        Grid::from_rows(["abc".chars(), "def".chars()]).unwrap()
+    }
+
+    fn p(x: isize, y: isize) -> Point2D {
+        Point2D::new(x, y)
+    }
+
+    #[test]
+    fn test_from_vec() {
+        let grid = Grid::from_vec(2, vec![1, 2, 3, 4, 5, 6]).unwrap();
+        assert_eq!((grid.width(), grid.height()), (2, 3));
+        assert_eq!(grid[p(1, 2)], 6);
+        assert!(Grid::from_vec(4, vec![1, 2, 3, 4, 5, 6]).is_none());
+        assert!(Grid::from_vec(0, vec![1]).is_none());
+        assert_eq!(Grid::<u8>::from_vec(0, vec![]).unwrap().height(), 0);
+    }
+
+    #[test]
+    fn test_from_rows_ragged() {
+        assert_eq!(Grid::from_rows([vec![1, 2], vec![3, 4], vec![5]]), Err(2));
+    }
+
+    #[test]
+    fn test_from_rows_of_empty_rows() {
+        let grid = Grid::<u8>::from_rows([vec![], vec![], vec![]]).unwrap();
+        assert_eq!((grid.width(), grid.height()), (0, 0));
+        assert_eq!(grid.rows().count(), 0);
+        assert_eq!(grid.columns().count(), 0);
+        assert_eq!(grid, Grid::from_vec(0, vec![]).unwrap());
+        assert_eq!(Grid::filled(0, 3, 'x'), grid.map(|_| 'x'));
+    }
+
+    #[test]
+    fn test_from_vec_without_cells() {
+        let grid = Grid::<u8>::from_vec(3, vec![]).unwrap();
+        assert_eq!((grid.width(), grid.height()), (0, 0));
+        assert_eq!(grid.rows().count(), 0);
+        assert_eq!(grid.columns().count(), 0);
+        assert_eq!(Grid::filled(3, 0, 0u8), grid);
+    }
+
+    #[test]
+    fn test_transforms_of_empty_grid() {
+        let grid = Grid::<u8>::from_vec(3, vec![]).unwrap();
+        for transformed in [
+            grid.transpose(),
+            grid.rotate_clockwise(),
+            grid.rotate_counterclockwise(),
+            grid.flip_horizontal(),
+            grid.flip_vertical(),
+        ] {
+            assert_eq!((transformed.width(), transformed.height()), (0, 0));
+            assert_eq!(transformed.rows().count(), 0);
+        }
+    }
+
+    #[test]
+    fn test_filled() {
+        let grid = Grid::filled(3, 2, '.');
+        assert_eq!(grid.to_string(), "...\n...");
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = Grid::parse(&Input::from("#.\n.#\n"), |_, cell| Some(cell)).unwrap();
+        assert_eq!(grid, Grid::from_vec(2, b"#..#".to_vec()).unwrap());
+    }
+
+    #[test]
+    fn test_parse_unknown_cell() {
+        let err = Grid::parse(&Input::from("..\n.x"), |_, cell| {
+            (cell == b'.').then_some(())
+        })
+        .unwrap_err();
+        assert_eq!(err, Error::parse(2, 2, "unknown cell 'x'"));
+    }
+
+    #[test]
+    fn test_parse_cell_depends_on_point() {
+        let start_in_first_line =
+            |point: Point2D, cell| (cell != b'S' || point.y == 0).then_some(cell);
+        assert!(Grid::parse(&Input::from(".S.\n..."), start_in_first_line).is_ok());
+        let err = Grid::parse(&Input::from("...\n.S."), start_in_first_line).unwrap_err();
+        assert_eq!(err, Error::parse(2, 2, "unknown cell 'S'"));
+    }
+
+    #[test]
+    fn test_parse_ragged_lines() {
+        let err = Grid::parse(&Input::from("...\n..\n..."), |_, cell| Some(cell)).unwrap_err();
+        assert_eq!(
+            err,
+            Error::parse(2, 3, "expected 3 cells like the first line")
+        );
+    }
+
+    #[test]
+    fn test_parse_empty() {
+        let grid = Grid::parse(&Input::from(""), |_, cell| Some(cell)).unwrap();
+        assert_eq!((grid.width(), grid.height()), (0, 0));
+    }
+
+    #[test]
+    fn test_checked_indexing() {
+        let mut grid = letters();
+        assert_eq!(grid.get(p(2, 1)), Some(&'f'));
+        assert_eq!(grid.get(p(3, 0)), None);
+        assert_eq!(grid.get(p(0, -1)), None);
+        assert!(!grid.contains(p(0, 2)));
+
+        *grid.get_mut(p(0, 1)).unwrap() = 'x';
+        grid[p(1, 0)] = 'y';
+        assert_eq!(grid.to_string(), "ayc\nxef");
+    }
+
+    #[test]
+    #[should_panic(expected = "point (3, 0) outside of the 3x2 grid")]
+    fn test_index_outside_panics() {
+        let _ = letters()[p(3, 0)];
+    }
+
+    #[test]
+    fn test_neighbours4() {
+        let grid = letters();
+        let corner: Vec<Point2D> = grid.neighbours4(p(0, 0)).collect();
+        assert_eq!(corner, vec![p(1, 0), p(0, 1)]);
+        assert_eq!(grid.neighbours4(p(1, 1)).count(), 3);
+    }
+
+    #[test]
+    fn test_neighbours8() {
+        let grid = letters();
+        let cells: String = grid.neighbours8(p(1, 0)).map(|n| grid[n]).collect();
+        assert_eq!(cells, "acdef");
+        assert_eq!(grid.neighbours8(p(5, 5)).count(), 0);
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = letters();
+        assert_eq!(grid.row(1), ['d', 'e', 'f']);
+        assert_eq!(grid.rows().next_back(), Some(['d', 'e', 'f'].as_slice()));
+        let columns: Vec<String> = grid.columns().map(|column| column.collect()).collect();
+        assert_eq!(columns, vec!["ad", "be", "cf"]);
+    }
+
+    #[test]
+    fn test_points_and_iter() {
+        let grid = letters();
+        assert_eq!(grid.points().nth(4), Some(p(1, 1)));
+        assert_eq!(grid.iter().last(), Some((p(2, 1), &'f')));
+    }
+
+    #[test]
+    fn test_find_and_position() {
+        let grid = letters();
+        assert_eq!(grid.find(&'e'), Some(p(1, 1)));
+        assert_eq!(grid.find(&'z'), None);
+        assert_eq!(grid.position(|&c| c > 'b'), Some(p(2, 0)));
+        let vowels: Vec<Point2D> = grid.positions(|c| "ae".contains(*c)).collect();
+        assert_eq!(vowels, vec![p(0, 0), p(1, 1)]);
+    }
+
+    #[test]
+    fn test_map() {
+        let grid = letters().map(|&c| c as u32 - 'a' as u32);
+        assert_eq!(grid.to_string(), "012\n345");
+    }
+
+    #[test]
+    fn test_transpose() {
+        let grid = letters().transpose();
+        assert_eq!((grid.width(), grid.height()), (2, 3));
+        assert_eq!(grid.to_string(), "ad\nbe\ncf");
+    }
+
+    #[test]
+    fn test_rotate() {
+        assert_eq!(letters().rotate_clockwise().to_string(), "da\neb\nfc");
+        assert_eq!(
+            letters().rotate_counterclockwise().to_string(),
+            "cf\nbe\nad"
+        );
+        assert_eq!(
+            letters().rotate_clockwise().rotate_counterclockwise(),
+            letters()
+        );
+    }
+
+    #[test]
+    fn test_flip() {
+        assert_eq!(letters().flip_horizontal().to_string(), "cba\nfed");
+        assert_eq!(letters().flip_vertical().to_string(), "def\nabc");
+    }
+}
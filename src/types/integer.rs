@@ -0,0 +1,148 @@
+use std::{fmt, hash::Hash, str::FromStr};
+
+/// Primitive integer a [`Range`](crate::types::ranges::Range) can be made of
+pub trait Integer:
+    Copy + Ord + Hash + fmt::Debug + fmt::Display + FromStr + Send + Sync + 'static
+{
+    /// Signed integer of the same width, to shift by
+    type Offset: Copy;
+
+    const MIN: Self;
+    const MAX: Self;
+
+    fn checked_add_one(self) -> Option<Self>;
+    fn checked_sub_one(self) -> Option<Self>;
+    fn checked_add_offset(self, offset: Self::Offset) -> Option<Self>;
+
+    /// `|end - start|`, which fits into `u128` for every pair of values
+    fn distance(start: Self, end: Self) -> u128;
+
+    /// Count of numbers in `start..=end`, which must not be empty.
+    ///
+    /// `None` for the one range that covers all 2^128 values of `u128` or `i128`.
+    fn count(start: Self, end: Self) -> Option<u128> {
+        Self::distance(start, end).checked_add(1)
+    }
+
+    /// `self + n`, `None` if that lies above `MAX`
+    fn forward(self, n: u128) -> Option<Self>;
+
+    /// `self - n`, `None` if that lies below `MIN`
+    fn backward(self, n: u128) -> Option<Self>;
+}
+
+macro_rules! impl_integer {
+    ($($t:ty => $offset:ty, $add_offset:ident, $unsigned:ty, $add_unsigned:ident);*) => {
+        $(impl Integer for $t {
+            type Offset = $offset;
+
+            const MIN: Self = <$t>::MIN;
+            const MAX: Self = <$t>::MAX;
+
+            fn checked_add_one(self) -> Option<Self> {
+                self.checked_add(1)
+            }
+
+            fn checked_sub_one(self) -> Option<Self> {
+                self.checked_sub(1)
+            }
+
+            fn checked_add_offset(self, offset: $offset) -> Option<Self> {
+                self.$add_offset(offset)
+            }
+
+            #[allow(clippy::unnecessary_cast)]
+            fn distance(start: Self, end: Self) -> u128 {
+                end.abs_diff(start) as u128
+            }
+
+            // both step through the distance to `MIN`, which always fits the unsigned type of that width
+            #[allow(clippy::unnecessary_cast)]
+            fn forward(self, n: u128) -> Option<Self> {
+                let distance = (self.abs_diff(Self::MIN) as u128).checked_add(n)?;
+                Self::MIN.$add_unsigned(<$unsigned>::try_from(distance).ok()?)
+            }
+
+            #[allow(clippy::unnecessary_cast)]
+            fn backward(self, n: u128) -> Option<Self> {
+                let distance = (self.abs_diff(Self::MIN) as u128).checked_sub(n)?;
+                Self::MIN.$add_unsigned(<$unsigned>::try_from(distance).ok()?)
+            }
+        })*
+    };
+}
+
+impl_integer!(
+    u8 => i8, checked_add_signed, u8, checked_add;
+    u16 => i16, checked_add_signed, u16, checked_add;
+    u32 => i32, checked_add_signed, u32, checked_add;
+    u64 => i64, checked_add_signed, u64, checked_add;
+    u128 => i128, checked_add_signed, u128, checked_add;
+    usize => isize, checked_add_signed, usize, checked_add;
+    i8 => i8, checked_add, u8, checked_add_unsigned;
+    i16 => i16, checked_add, u16, checked_add_unsigned;
+    i32 => i32, checked_add, u32, checked_add_unsigned;
+    i64 => i64, checked_add, u64, checked_add_unsigned;
+    i128 => i128, checked_add, u128, checked_add_unsigned;
+    isize => isize, checked_add, usize, checked_add_unsigned
+);
+
+/// Integer whose ranges never hold more than `usize::MAX` numbers, so that their
+/// [`RangeIter`](crate::types::ranges::RangeIter) is an `ExactSizeIterator`.
+///
+/// Like `RangeInclusive` in std this leaves out `usize` and `isize` themselves,
+/// whose full range holds `usize::MAX + 1` numbers.
+pub trait ExactSizeInteger: Integer {}
+
+impl ExactSizeInteger for u8 {}
+impl ExactSizeInteger for i8 {}
+impl ExactSizeInteger for u16 {}
+impl ExactSizeInteger for i16 {}
+#[cfg(target_pointer_width = "64")]
+impl ExactSizeInteger for u32 {}
+#[cfg(target_pointer_width = "64")]
+impl ExactSizeInteger for i32 {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_count() {
+        assert_eq!(u8::count(0, 255), Some(256));
+        assert_eq!(i8::count(-128, 127), Some(256));
+        assert_eq!(i64::count(i64::MIN, i64::MAX), Some(1 << 64));
+        assert_eq!(u128::count(1, u128::MAX), Some(u128::MAX));
+        assert_eq!(i32::count(-3, -3), Some(1));
+        assert_eq!(u128::count(0, u128::MAX), None);
+        assert_eq!(i128::count(i128::MIN, i128::MAX), None);
+    }
+
+    #[test]
+    fn test_distance() {
+        assert_eq!(u128::distance(0, u128::MAX), u128::MAX);
+        assert_eq!(i128::distance(i128::MAX, i128::MIN), u128::MAX);
+        assert_eq!(i8::distance(-3, -3), 0);
+    }
+
+    #[test]
+    fn test_steps_and_offsets() {
+        assert_eq!(u8::MAX.checked_add_one(), None);
+        assert_eq!(i8::MIN.checked_sub_one(), None);
+        assert_eq!(5u32.checked_add_offset(-5), Some(0));
+        assert_eq!(5u32.checked_add_offset(-6), None);
+        assert_eq!((-5i64).checked_add_offset(-6), Some(-11));
+    }
+
+    #[test]
+    fn test_forward_and_backward() {
+        assert_eq!(250u8.forward(5), Some(255));
+        assert_eq!(250u8.forward(6), None);
+        assert_eq!(i8::MIN.forward(255), Some(i8::MAX));
+        assert_eq!((-3i32).forward(u128::MAX), None);
+        assert_eq!(i128::MIN.forward(u128::MAX), Some(i128::MAX));
+        assert_eq!(u128::MAX.backward(u128::MAX), Some(0));
+        assert_eq!(5i16.backward(10), Some(-5));
+        assert_eq!(5u16.backward(10), None);
+    }
+}
@@ -1,3 +1,6 @@
+pub mod answer;
+pub mod grid;
+pub mod integer;
 pub mod point3d;
 pub mod ranges;
 pub mod union_find;
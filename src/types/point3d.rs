@@ -1,3 +1,11 @@
+/// Point in space, parsed from "x,y,z".
+///
+/// ```
+/// use advent_of_code::types::point3d::Point3D;
+///
+/// let point = Point3D::from_string("1, 2, 2").unwrap();
+/// assert_eq!(point.distance_to(&Point3D::new(0, 0, 0)), 3.0);
+/// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Point3D {
     pub x: isize,
@@ -29,6 +37,15 @@ impl Point3D {
     }
 }
 
+/// Point in the plane, parsed from "x,y".
+///
+/// ```
+/// use advent_of_code::types::point3d::Point2D;
+///
+/// let (one, two) = (Point2D::new(2, 5), Point2D::from_string("11,1").unwrap());
+/// assert_eq!(Point2D::square_between(&one, &two), 50);
+/// assert!(Point2D::new(7, 3).inside_rectangle(&one, &two));
+/// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Point2D {
     pub x: isize,
@@ -40,7 +57,6 @@ impl Point2D {
         Point2D { x, y }
     }
 
-    #[allow(unused)]
     pub fn distance_to(&self, other: &Point2D) -> f64 {
         let dx = (self.x - other.x) as f64;
         let dy = (self.y - other.y) as f64;
@@ -221,6 +237,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::clone_on_copy)]
     fn test_clone() {
         let p1 = Point3D::new(1, 2, 3);
         let p2 = p1.clone();
@@ -1,51 +1,694 @@
-#[derive(Clone, Copy, PartialEq, Eq, Debug, PartialOrd, Ord)]
-pub struct Range {
-    pub start: usize,
-    pub end: usize,
+use std::{any::type_name, fmt, iter::FusedIterator, str::FromStr};
+
+use rayon::iter::{
+    IntoParallelIterator, ParallelIterator,
+    plumbing::{Folder, UnindexedConsumer, UnindexedProducer, bridge_unindexed},
+};
+
+use crate::types::integer::{ExactSizeInteger, Integer};
+
+/// Inclusive range of `start..=end` over any primitive integer, its [`RangeIter`] yields every number in it.
+///
+/// ```
+/// use advent_of_code::types::ranges::Range;
+///
+/// let range: Range = "3-5".parse().unwrap();
+/// assert_eq!(range.size(), Some(3));
+/// assert!(range.contains(5));
+/// assert_eq!(range.into_iter().collect::<Vec<_>>(), vec![3, 4, 5]);
+///
+/// let signed: Range<i64> = "-3--1".parse().unwrap();
+/// assert_eq!(signed.into_iter().rev().collect::<Vec<_>>(), vec![-1, -2, -3]);
+/// assert_eq!(Range::new(0, u64::MAX).size(), Some(1 << 64));
+/// assert_eq!(Range::new(0, u128::MAX).size(), None);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Debug, PartialOrd, Ord, Hash)]
+pub struct Range<T = usize> {
+    pub start: T,
+    pub end: T,
 }
 
-impl Range {
-    pub fn new(start: usize, end: usize) -> Self {
+impl<T: Integer> Range<T> {
+    pub fn new(start: T, end: T) -> Self {
         Range { start, end }
     }
 
-    pub fn size(&self) -> usize {
-        self.end - self.start + 1
+    /// Count of numbers in the range, 0 if it is empty.
+    ///
+    /// `None` only for the range spanning all of `u128` or `i128`, whose size does not fit into `u128`.
+    pub fn size(&self) -> Option<u128> {
+        match self.is_empty() {
+            true => Some(0),
+            false => T::count(self.start, self.end),
+        }
     }
 
-    pub fn contains(&self, n: usize) -> bool {
+    pub fn contains(&self, n: T) -> bool {
         n >= self.start && n <= self.end
     }
 
-    pub fn from_string(line: &str) -> Option<Self> {
-        let mut parts = line.split('-');
-        let start: usize = parts.next()?.parse().ok()?;
-        let end: usize = parts.next()?.parse().ok()?;
-        Some(Range::new(start, end))
+    /// Whether `start` lies after `end`, e.g. for a reversed range like "10-5"
+    pub fn is_empty(&self) -> bool {
+        self.start > self.end
+    }
+
+    /// Parses `a-b` or `a..=b` as `a` to `b`, and `a..b` as `a` to `b - 1`.
+    ///
+    /// Either bound may be negative, like in `"-5--1"` or `"-5..0"`. The whole text must be
+    /// the range, so `"1-5-10"` is an error. A range written from high to low, like `"10-5"`,
+    /// is handled as `reversed` says, while `"5..5"` is simply empty.
+    ///
+    /// ```
+    /// use advent_of_code::types::ranges::{ParseRangeError, Range, Reversed};
+    ///
+    /// assert_eq!(Range::parse_with("10-5", Reversed::Swap), Ok(Range::new(5, 10)));
+    /// assert!(Range::<u8>::parse_with("10-5", Reversed::Empty).unwrap().is_empty());
+    /// assert_eq!(
+    ///     Range::<u8>::parse_with("10-5", Reversed::Reject),
+    ///     Err(ParseRangeError::Reversed { start: "10".into(), end: "5".into() })
+    /// );
+    /// ```
+    pub fn parse_with(text: &str, reversed: Reversed) -> Result<Self, ParseRangeError> {
+        if text.is_empty() {
+            return Err(ParseRangeError::Empty);
+        }
+        let (start, end, exclusive) = match text.split_once("..") {
+            Some((start, end)) => match end.strip_prefix('=') {
+                Some(end) => (start, end, false),
+                None => (start, end, true),
+            },
+            // the dash between the bounds is the first one after the first character
+            None => match text.get(1..).and_then(|rest| rest.find('-')) {
+                Some(dash) => (&text[..dash + 1], &text[dash + 2..], false),
+                None => return Err(ParseRangeError::MissingSeparator(text.to_string())),
+            },
+        };
+        let bound = |bound: &str| {
+            if bound.is_empty() {
+                return Err(ParseRangeError::MissingBound(text.to_string()));
+            }
+            bound
+                .parse::<T>()
+                .map_err(|_| ParseRangeError::InvalidBound {
+                    bound: bound.to_string(),
+                    kind: type_name::<T>(),
+                })
+        };
+        let (mut low, mut high) = (bound(start)?, bound(end)?);
+
+        if high < low {
+            match reversed {
+                Reversed::Reject => {
+                    return Err(ParseRangeError::Reversed {
+                        start: start.to_string(),
+                        end: end.to_string(),
+                    });
+                }
+                Reversed::Empty => return Ok(Range::new(low, high)),
+                Reversed::Swap => (low, high) = (high, low),
+            }
+        }
+        if !exclusive {
+            return Ok(Range::new(low, high));
+        }
+        Ok(match high.checked_sub_one() {
+            Some(last) => Range::new(low, last),
+            // only `MIN..MIN` gets here, which is empty
+            None => Range::new(low.forward(1).expect("T has more than one value"), high),
+        })
     }
 
-    pub fn overlaps_with(&self, other: &Range) -> bool {
+    pub fn overlaps_with(&self, other: &Range<T>) -> bool {
         self.start <= other.end && self.end >= other.start
     }
 
-    pub fn merge_into(&mut self, other: &Range) {
+    pub fn merge_into(&mut self, other: &Range<T>) {
         if self.overlaps_with(other) {
             self.end = self.end.max(other.end);
         }
     }
+
+    /// Whether `other` starts right after this range ends or the other way round
+    pub fn touches(&self, other: &Range<T>) -> bool {
+        self.end.checked_add_one() == Some(other.start)
+            || other.end.checked_add_one() == Some(self.start)
+    }
+
+    /// Numbers in both ranges, `None` if they do not overlap
+    pub fn intersect(&self, other: &Range<T>) -> Option<Range<T>> {
+        let overlap = Range::new(self.start.max(other.start), self.end.min(other.end));
+        (!overlap.is_empty()).then_some(overlap)
+    }
+
+    /// Single range covering both, `None` if there is a gap between them
+    pub fn union(&self, other: &Range<T>) -> Option<Range<T>> {
+        match (self.is_empty(), other.is_empty()) {
+            (true, _) => Some(*other),
+            (_, true) => Some(*self),
+            _ if self.overlaps_with(other) || self.touches(other) => Some(Range::new(
+                self.start.min(other.start),
+                self.end.max(other.end),
+            )),
+            _ => None,
+        }
+    }
+
+    /// Numbers of this range that are not in `other`: none, one or two pieces in ascending order
+    ///
+    /// ```
+    /// use advent_of_code::types::ranges::Range;
+    ///
+    /// let pieces: Vec<Range> = Range::new(1, 10).subtract(&Range::new(4, 6)).collect();
+    /// assert_eq!(pieces, vec![Range::new(1, 3), Range::new(7, 10)]);
+    /// assert_eq!(Range::new(4, 6).subtract(&Range::new(1, 10)).count(), 0);
+    /// ```
+    pub fn subtract(&self, other: &Range<T>) -> impl Iterator<Item = Range<T>> + use<T> {
+        let pieces = match self.intersect(other) {
+            None if self.is_empty() => [None, None],
+            None => [Some(*self), None],
+            Some(overlap) => [
+                overlap
+                    .start
+                    .checked_sub_one()
+                    .filter(|_| overlap.start > self.start)
+                    .map(|last| Range::new(self.start, last)),
+                overlap
+                    .end
+                    .checked_add_one()
+                    .filter(|_| overlap.end < self.end)
+                    .map(|first| Range::new(first, self.end)),
+            ],
+        };
+        pieces.into_iter().flatten()
+    }
+
+    /// Numbers below `at` and numbers from `at` on, `None` for an empty side
+    ///
+    /// ```
+    /// use advent_of_code::types::ranges::Range;
+    ///
+    /// assert_eq!(
+    ///     Range::new(1, 10).split_at(4),
+    ///     (Some(Range::new(1, 3)), Some(Range::new(4, 10)))
+    /// );
+    /// assert_eq!(Range::new(1, 10).split_at(1), (None, Some(Range::new(1, 10))));
+    /// ```
+    pub fn split_at(&self, at: T) -> (Option<Range<T>>, Option<Range<T>>) {
+        let below = at
+            .checked_sub_one()
+            .map(|last| Range::new(self.start, self.end.min(last)))
+            .filter(|range| !range.is_empty());
+        let from = Range::new(self.start.max(at), self.end);
+        (below, (!from.is_empty()).then_some(from))
+    }
+
+    /// Range moved by `offset`, `None` if it would leave `T`
+    pub fn shift(&self, offset: T::Offset) -> Option<Range<T>> {
+        Some(Range::new(
+            self.start.checked_add_offset(offset)?,
+            self.end.checked_add_offset(offset)?,
+        ))
+    }
+}
+
+/// What [`Range::parse_with`] does with a range written from high to low like `"10-5"`
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum Reversed {
+    /// Fails with [`ParseRangeError::Reversed`], used by `FromStr`
+    #[default]
+    Reject,
+    /// Keeps the bounds as written, which makes the range empty
+    Empty,
+    /// Swaps the bounds, so `"10-5"` is 5 to 10 and `"5..2"` is 2 to 4
+    Swap,
+}
+
+/// Why a text is no [`Range`]
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum ParseRangeError {
+    Empty,
+    /// Neither `-`, `..` nor `..=` between the bounds
+    MissingSeparator(String),
+    /// The whole text, whose start or end is missing
+    MissingBound(String),
+    InvalidBound {
+        bound: String,
+        kind: &'static str,
+    },
+    Reversed {
+        start: String,
+        end: String,
+    },
+}
+
+impl fmt::Display for ParseRangeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseRangeError::Empty => write!(f, "empty range"),
+            ParseRangeError::MissingSeparator(text) => {
+                write!(f, "expected \"-\", \"..\" or \"..=\" in \"{}\"", text)
+            }
+            ParseRangeError::MissingBound(text) => write!(f, "missing bound in \"{}\"", text),
+            ParseRangeError::InvalidBound { bound, kind } => {
+                write!(f, "cannot parse \"{}\" as {}", bound, kind)
+            }
+            ParseRangeError::Reversed { start, end } => {
+                write!(f, "reversed range, {} is greater than {}", start, end)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseRangeError {}
+
+/// Strict parsing with [`Reversed::Reject`], see [`Range::parse_with`]
+///
+/// ```
+/// use advent_of_code::types::ranges::Range;
+///
+/// let range: Range<i32> = "-2..3".parse().unwrap();
+/// assert_eq!((range.start, range.end), (-2, 2));
+/// assert_eq!(range.to_string(), "-2-2");
+/// assert_eq!(
+///     "1-5-10".parse::<Range>().unwrap_err().to_string(),
+///     "cannot parse \"5-10\" as usize"
+/// );
+/// ```
+impl<T: Integer> FromStr for Range<T> {
+    type Err = ParseRangeError;
+
+    fn from_str(text: &str) -> Result<Self, ParseRangeError> {
+        Range::parse_with(text, Reversed::Reject)
+    }
+}
+
+impl<T: Integer> TryFrom<&str> for Range<T> {
+    type Error = ParseRangeError;
+
+    fn try_from(text: &str) -> Result<Self, ParseRangeError> {
+        text.parse()
+    }
+}
+
+/// Writes `start-end`, which parses back into the same range unless it is empty
+impl<T: Integer> fmt::Display for Range<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{}", self.start, self.end)
+    }
+}
+
+impl<T: Integer> IntoIterator for Range<T> {
+    type Item = T;
+    type IntoIter = RangeIter<T>;
+
+    fn into_iter(self) -> RangeIter<T> {
+        RangeIter {
+            front: self.start,
+            back: self.end,
+            exhausted: self.is_empty(),
+        }
+    }
+}
+
+impl<T: Integer> IntoIterator for &Range<T> {
+    type Item = T;
+    type IntoIter = RangeIter<T>;
+
+    fn into_iter(self) -> RangeIter<T> {
+        (*self).into_iter()
+    }
+}
+
+/// Iterator over the numbers of a [`Range`] from both ends, `nth` and `step_by` jump in O(1).
+///
+/// It never steps past `T::MAX` or `T::MIN`, so `Range::new(250u8, u8::MAX)` yields 250 to 255.
+/// `len` is only there for types whose every range fits into `usize`, see
+/// [`ExactSizeInteger`]; for longer ranges `size_hint` has no upper bound.
+///
+/// ```
+/// use advent_of_code::types::ranges::Range;
+///
+/// let mut numbers = Range::new(1u32, 10).into_iter();
+/// assert_eq!((numbers.next(), numbers.next_back()), (Some(1), Some(10)));
+/// assert_eq!(numbers.len(), 8);
+/// assert_eq!(numbers.step_by(3).collect::<Vec<_>>(), vec![2, 5, 8]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct RangeIter<T> {
+    front: T,
+    back: T,
+    exhausted: bool,
+}
+
+impl<T: Integer> RangeIter<T> {
+    /// Numbers left after the next one, `None` when nothing is left.
+    ///
+    /// Unlike the count of numbers left this always fits into `u128`.
+    fn after_next(&self) -> Option<u128> {
+        match self.exhausted {
+            true => None,
+            false => Some(T::distance(self.front, self.back)),
+        }
+    }
+
+    fn exhaust(&mut self) -> Option<T> {
+        self.exhausted = true;
+        None
+    }
+}
+
+impl<T: Integer> Iterator for RangeIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.exhausted {
+            return None;
+        }
+        let current = self.front;
+        // `front < back` here, so stepping cannot overflow
+        match self.front == self.back {
+            true => self.exhausted = true,
+            false => self.front = self.front.forward(1)?,
+        }
+        Some(current)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self
+            .after_next()
+            .map_or(Some(0), |after| after.checked_add(1));
+        match len.and_then(|len| usize::try_from(len).ok()) {
+            Some(len) => (len, Some(len)),
+            None => (usize::MAX, None),
+        }
+    }
+
+    fn nth(&mut self, n: usize) -> Option<T> {
+        if self.after_next().is_none_or(|after| n as u128 > after) {
+            return self.exhaust();
+        }
+        self.front = self.front.forward(n as u128)?;
+        self.next()
+    }
+
+    fn last(mut self) -> Option<T> {
+        self.next_back()
+    }
+
+    fn min(mut self) -> Option<T> {
+        self.next()
+    }
+
+    fn max(mut self) -> Option<T> {
+        self.next_back()
+    }
+}
+
+impl<T: Integer> DoubleEndedIterator for RangeIter<T> {
+    fn next_back(&mut self) -> Option<T> {
+        if self.exhausted {
+            return None;
+        }
+        let current = self.back;
+        match self.front == self.back {
+            true => self.exhausted = true,
+            false => self.back = self.back.backward(1)?,
+        }
+        Some(current)
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<T> {
+        if self.after_next().is_none_or(|after| n as u128 > after) {
+            return self.exhaust();
+        }
+        self.back = self.back.backward(n as u128)?;
+        self.next_back()
+    }
+}
+
+impl<T: ExactSizeInteger> ExactSizeIterator for RangeIter<T> {}
+
+impl<T: Integer> FusedIterator for RangeIter<T> {}
+
+impl<T: Integer> IntoParallelIterator for Range<T> {
+    type Iter = ParRangeIter<T>;
+    type Item = T;
+
+    fn into_par_iter(self) -> ParRangeIter<T> {
+        ParRangeIter {
+            iter: self.into_iter(),
+        }
+    }
+}
+
+impl<T: Integer> IntoParallelIterator for &Range<T> {
+    type Iter = ParRangeIter<T>;
+    type Item = T;
+
+    fn into_par_iter(self) -> ParRangeIter<T> {
+        (*self).into_par_iter()
+    }
+}
+
+/// Parallel iterator over the numbers of a [`Range`], splitting it in halves for rayon.
+///
+/// ```
+/// use advent_of_code::types::ranges::Range;
+/// use rayon::prelude::*;
+///
+/// let sum: u64 = Range::new(1u64, 1_000_000).into_par_iter().sum();
+/// assert_eq!(sum, 500_000_500_000);
+/// ```
+#[derive(Clone, Debug)]
+pub struct ParRangeIter<T> {
+    iter: RangeIter<T>,
+}
+
+impl<T: Integer> ParallelIterator for ParRangeIter<T> {
+    type Item = T;
+
+    fn drive_unindexed<C: UnindexedConsumer<T>>(self, consumer: C) -> C::Result {
+        bridge_unindexed(self.iter, consumer)
+    }
+}
+
+impl<T: Integer> UnindexedProducer for RangeIter<T> {
+    type Item = T;
+
+    fn split(self) -> (Self, Option<Self>) {
+        let after = match self.after_next() {
+            Some(after) if after > 0 => after,
+            _ => return (self, None),
+        };
+        // half of the `after + 1` numbers stay in front, rounded up, without computing `after + 1`
+        let half = after / 2 + after % 2;
+        let mid = self
+            .front
+            .forward(half)
+            .expect("middle lies inside the range");
+        let upper = RangeIter {
+            front: mid,
+            back: self.back,
+            exhausted: false,
+        };
+        let lower = RangeIter {
+            front: self.front,
+            back: mid.backward(1).expect("middle lies after the front"),
+            exhausted: false,
+        };
+        (lower, Some(upper))
+    }
+
+    fn fold_with<F: Folder<T>>(self, folder: F) -> F {
+        folder.consume_iter(self)
+    }
+}
+
+/// Numbers covered by a set of ranges, kept sorted, disjoint and without adjacent ranges,
+/// so `1-3` and `4-6` are stored as `1-6`.
+///
+/// ```
+/// use advent_of_code::types::ranges::{Range, RangeSet};
+///
+/// let fresh: RangeSet = [Range::new(3, 5), Range::new(10, 14), Range::new(16, 20), Range::new(12, 18)]
+///     .into_iter()
+///     .collect();
+/// assert_eq!(fresh.ranges(), [Range::new(3, 5), Range::new(10, 20)]);
+/// assert!(fresh.contains(17) && !fresh.contains(8));
+/// assert_eq!(fresh.size(), Some(14));
+/// assert_eq!(fresh.gaps().collect::<Vec<_>>(), vec![Range::new(6, 9)]);
+/// ```
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct RangeSet<T = usize> {
+    ranges: Vec<Range<T>>,
+}
+
+impl<T> Default for RangeSet<T> {
+    fn default() -> Self {
+        RangeSet { ranges: Vec::new() }
+    }
 }
 
-impl Iterator for Range {
-    type Item = usize;
+impl<T: Integer> RangeSet<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The sorted, disjoint ranges of the set
+    pub fn ranges(&self) -> &[Range<T>] {
+        &self.ranges
+    }
+
+    /// Number of disjoint ranges
+    pub fn len(&self) -> usize {
+        self.ranges.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, Range<T>> {
+        self.ranges.iter()
+    }
+
+    /// Adds `range`, merging it with the ranges it overlaps or touches; empty ranges are ignored
+    pub fn insert(&mut self, range: Range<T>) {
+        if range.is_empty() {
+            return;
+        }
+        // ranges[first..last] overlap or touch `range`
+        let first = self.ranges.partition_point(|r| {
+            r.end
+                .checked_add_one()
+                .is_some_and(|next| next < range.start)
+        });
+        let last = self.ranges.partition_point(|r| {
+            r.start
+                .checked_sub_one()
+                .is_none_or(|before| before <= range.end)
+        });
+        let merged = match first < last {
+            true => Range::new(
+                range.start.min(self.ranges[first].start),
+                range.end.max(self.ranges[last - 1].end),
+            ),
+            false => range,
+        };
+        self.ranges.splice(first..last, [merged]);
+    }
+
+    /// Removes every number of `range` from the set
+    pub fn remove(&mut self, range: Range<T>) {
+        *self = self.difference(&RangeSet::from_iter([range]));
+    }
+
+    /// Whether any range contains `n`, in O(log n)
+    pub fn contains(&self, n: T) -> bool {
+        let index = self.ranges.partition_point(|r| r.end < n);
+        self.ranges.get(index).is_some_and(|r| r.start <= n)
+    }
+
+    /// Count of all numbers in the set, `None` if it does not fit into `u128`
+    pub fn size(&self) -> Option<u128> {
+        self.ranges
+            .iter()
+            .try_fold(0u128, |size, range| size.checked_add(range.size()?))
+    }
+
+    pub fn union(&self, other: &RangeSet<T>) -> RangeSet<T> {
+        let mut union = self.clone();
+        union.extend(other.iter().copied());
+        union
+    }
+
+    pub fn intersection(&self, other: &RangeSet<T>) -> RangeSet<T> {
+        let (a, b) = (&self.ranges, &other.ranges);
+        let mut ranges = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while i < a.len() && j < b.len() {
+            if let Some(overlap) = a[i].intersect(&b[j]) {
+                ranges.push(overlap);
+            }
+            match a[i].end < b[j].end {
+                true => i += 1,
+                false => j += 1,
+            }
+        }
+        RangeSet { ranges }
+    }
+
+    /// Numbers of this set that are not in `other`
+    pub fn difference(&self, other: &RangeSet<T>) -> RangeSet<T> {
+        match (self.ranges.first(), self.ranges.last()) {
+            (Some(first), Some(last)) => {
+                self.intersection(&other.complement(Range::new(first.start, last.end)))
+            }
+            _ => RangeSet::new(),
+        }
+    }
 
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.start <= self.end {
-            let current = self.start;
-            self.start += 1;
-            Some(current)
-        } else {
-            None
+    /// Numbers within `bound` that are not in the set
+    pub fn complement(&self, bound: Range<T>) -> RangeSet<T> {
+        let mut ranges = Vec::new();
+        // first number not yet covered, `None` once the end of `T` is covered
+        let mut cursor = Some(bound.start);
+        for range in &self.ranges {
+            let Some(next) = cursor else { break };
+            if range.start > bound.end {
+                break;
+            }
+            if range.end < next {
+                continue;
+            }
+            if let Some(before) = range.start.checked_sub_one()
+                && range.start > next
+            {
+                ranges.push(Range::new(next, before));
+            }
+            cursor = range.end.checked_add_one();
+        }
+        if let Some(next) = cursor
+            && next <= bound.end
+        {
+            ranges.push(Range::new(next, bound.end));
         }
+        RangeSet { ranges }
+    }
+
+    /// Ranges between consecutive ranges of the set
+    pub fn gaps(&self) -> impl Iterator<Item = Range<T>> + '_ {
+        self.ranges.windows(2).filter_map(|pair| {
+            Some(Range::new(
+                pair[0].end.checked_add_one()?,
+                pair[1].start.checked_sub_one()?,
+            ))
+        })
+    }
+}
+
+impl<T: Integer> Extend<Range<T>> for RangeSet<T> {
+    fn extend<I: IntoIterator<Item = Range<T>>>(&mut self, ranges: I) {
+        for range in ranges {
+            self.insert(range);
+        }
+    }
+}
+
+impl<T: Integer> FromIterator<Range<T>> for RangeSet<T> {
+    fn from_iter<I: IntoIterator<Item = Range<T>>>(ranges: I) -> Self {
+        let mut set = RangeSet::new();
+        set.extend(ranges);
+        set
+    }
+}
+
+impl<'a, T> IntoIterator for &'a RangeSet<T> {
+    type Item = &'a Range<T>;
+    type IntoIter = std::slice::Iter<'a, Range<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.ranges.iter()
     }
 }
 
@@ -56,7 +699,7 @@ mod tests {
     #[test]
     fn test_range_size() {
         let r = Range::new(1, 5);
-        assert_eq!(r.size(), 5);
+        assert_eq!(r.size(), Some(5));
     }
 
     #[test]
@@ -89,107 +732,114 @@ mod tests {
     }
 
     #[test]
-    fn test_range_from_string_basic() {
-        let r = Range::from_string("1-5");
-        assert!(r.is_some());
+    fn test_range_parse_basic() {
+        let r = "1-5".parse::<Range>();
+        assert!(r.is_ok());
         let r = r.unwrap();
         assert_eq!(r.start, 1);
         assert_eq!(r.end, 5);
     }
 
     #[test]
-    fn test_range_from_string_large_numbers() {
-        let r = Range::from_string("100-999");
-        assert!(r.is_some());
+    fn test_range_parse_large_numbers() {
+        let r = "100-999".parse::<Range>();
+        assert!(r.is_ok());
         let r = r.unwrap();
         assert_eq!(r.start, 100);
         assert_eq!(r.end, 999);
     }
 
     #[test]
-    fn test_range_from_string_same_start_end() {
-        let r = Range::from_string("5-5");
-        assert!(r.is_some());
+    fn test_range_parse_same_start_end() {
+        let r = "5-5".parse::<Range>();
+        assert!(r.is_ok());
         let r = r.unwrap();
         assert_eq!(r.start, 5);
         assert_eq!(r.end, 5);
-        assert_eq!(r.size(), 1);
+        assert_eq!(r.size(), Some(1));
     }
 
     #[test]
-    fn test_range_from_string_zero() {
-        let r = Range::from_string("0-10");
-        assert!(r.is_some());
+    fn test_range_parse_zero() {
+        let r = "0-10".parse::<Range>();
+        assert!(r.is_ok());
         let r = r.unwrap();
         assert_eq!(r.start, 0);
         assert_eq!(r.end, 10);
     }
 
     #[test]
-    fn test_range_from_string_invalid_no_dash() {
-        let r = Range::from_string("1 5");
-        assert!(r.is_none());
+    fn test_range_parse_invalid_no_dash() {
+        let r = "1 5".parse::<Range>();
+        assert!(r.is_err());
     }
 
     #[test]
-    fn test_range_from_string_invalid_non_numeric() {
-        let r = Range::from_string("a-b");
-        assert!(r.is_none());
+    fn test_range_parse_invalid_non_numeric() {
+        let r = "a-b".parse::<Range>();
+        assert!(r.is_err());
     }
 
     #[test]
-    fn test_range_from_string_invalid_empty() {
-        let r = Range::from_string("");
-        assert!(r.is_none());
+    fn test_range_parse_invalid_empty() {
+        let r = "".parse::<Range>();
+        assert!(r.is_err());
     }
 
     #[test]
-    fn test_range_from_string_invalid_only_dash() {
-        let r = Range::from_string("-");
-        assert!(r.is_none());
+    fn test_range_parse_invalid_only_dash() {
+        let r = "-".parse::<Range>();
+        assert!(r.is_err());
     }
 
     #[test]
-    fn test_range_from_string_invalid_missing_end() {
-        let r = Range::from_string("5-");
-        assert!(r.is_none());
+    fn test_range_parse_invalid_missing_end() {
+        let r = "5-".parse::<Range>();
+        assert!(r.is_err());
     }
 
     #[test]
-    fn test_range_from_string_invalid_missing_start() {
-        let r = Range::from_string("-10");
-        assert!(r.is_none());
+    fn test_range_parse_invalid_missing_start() {
+        let r = "-10".parse::<Range>();
+        assert!(r.is_err());
     }
 
     #[test]
-    fn test_range_from_string_multiple_dashes() {
-        let r = Range::from_string("1-5-10");
-        assert!(r.is_some());
-        let r = r.unwrap();
-        assert_eq!(r.start, 1);
-        assert_eq!(r.end, 5);
+    fn test_range_parse_multiple_dashes() {
+        let r = "1-5-10".parse::<Range>();
+        assert_eq!(
+            r,
+            Err(ParseRangeError::InvalidBound {
+                bound: "5-10".to_string(),
+                kind: "usize"
+            })
+        );
     }
 
     #[test]
-    fn test_range_from_string_with_whitespace() {
-        let r = Range::from_string(" 1 - 5 ");
-        assert!(r.is_none());
+    fn test_range_parse_with_whitespace() {
+        let r = " 1 - 5 ".parse::<Range>();
+        assert!(r.is_err());
     }
 
     #[test]
-    fn test_range_from_string_reversed_range() {
-        // Das sollte funktionieren - es ist ein gültiger Range
-        let r = Range::from_string("10-5");
-        assert!(r.is_some());
-        let r = r.unwrap();
-        assert_eq!(r.start, 10);
-        assert_eq!(r.end, 5);
+    fn test_range_parse_reversed_range() {
+        let r = "10-5".parse::<Range>();
+        assert_eq!(
+            r,
+            Err(ParseRangeError::Reversed {
+                start: "10".to_string(),
+                end: "5".to_string()
+            })
+        );
+        let r = Range::<usize>::parse_with("10-5", Reversed::Empty).unwrap();
+        assert_eq!((r.start, r.end), (10, 5));
     }
 
     #[test]
-    fn test_range_from_string_very_large_numbers() {
-        let r = Range::from_string("1000000-9999999");
-        assert!(r.is_some());
+    fn test_range_parse_very_large_numbers() {
+        let r = "1000000-9999999".parse::<Range>();
+        assert!(r.is_ok());
         let r = r.unwrap();
         assert_eq!(r.start, 1000000);
         assert_eq!(r.end, 9999999);
@@ -197,7 +847,7 @@ mod tests {
 
     #[test]
     fn test_range_iterator_basic() {
-        let mut r = Range::new(1, 3);
+        let mut r = Range::new(1, 3).into_iter();
         assert_eq!(r.next(), Some(1));
         assert_eq!(r.next(), Some(2));
         assert_eq!(r.next(), Some(3));
@@ -206,7 +856,7 @@ mod tests {
 
     #[test]
     fn test_range_iterator_single_element() {
-        let mut r = Range::new(5, 5);
+        let mut r = Range::new(5, 5).into_iter();
         assert_eq!(r.next(), Some(5));
         assert_eq!(r.next(), None);
     }
@@ -224,7 +874,7 @@ mod tests {
 
     #[test]
     fn test_range_iterator_collect() {
-        let r = Range::new(1, 5);
+        let r = Range::new(1, 5).into_iter();
         let collected: Vec<usize> = r.collect();
         assert_eq!(collected, vec![1, 2, 3, 4, 5]);
     }
@@ -232,86 +882,86 @@ mod tests {
     #[test]
     fn test_range_iterator_empty_range() {
         // Wenn start > end, sollte gleich None zurückgeben
-        let mut r = Range::new(5, 3);
+        let mut r = Range::new(5, 3).into_iter();
         assert_eq!(r.next(), None);
     }
 
     #[test]
     fn test_range_iterator_zero_start() {
-        let r = Range::new(0, 3);
+        let r = Range::new(0, 3).into_iter();
         let collected: Vec<usize> = r.collect();
         assert_eq!(collected, vec![0, 1, 2, 3]);
     }
 
     #[test]
     fn test_range_iterator_large_numbers() {
-        let r = Range::new(1000, 1005);
+        let r = Range::new(1000, 1005).into_iter();
         let collected: Vec<usize> = r.collect();
         assert_eq!(collected, vec![1000, 1001, 1002, 1003, 1004, 1005]);
     }
 
     #[test]
     fn test_range_iterator_filter() {
-        let r = Range::new(1, 10);
+        let r = Range::new(1, 10).into_iter();
         let even: Vec<usize> = r.filter(|x| x % 2 == 0).collect();
         assert_eq!(even, vec![2, 4, 6, 8, 10]);
     }
 
     #[test]
     fn test_range_iterator_map() {
-        let r = Range::new(1, 3);
+        let r = Range::new(1, 3).into_iter();
         let doubled: Vec<usize> = r.map(|x| x * 2).collect();
         assert_eq!(doubled, vec![2, 4, 6]);
     }
 
     #[test]
     fn test_range_iterator_sum() {
-        let r = Range::new(1, 5);
+        let r = Range::new(1, 5).into_iter();
         let sum: usize = r.sum();
         assert_eq!(sum, 15); // 1+2+3+4+5 = 15
     }
 
     #[test]
     fn test_range_iterator_count() {
-        let r = Range::new(1, 10);
+        let r = Range::new(1, 10).into_iter();
         let count = r.count();
         assert_eq!(count, 10);
     }
 
     #[test]
     fn test_range_iterator_any() {
-        let r = Range::new(1, 10);
+        let r = Range::new(1, 10).into_iter();
         assert!(r.clone().any(|x| x == 5));
 
-        let mut r = Range::new(1, 10);
+        let mut r = Range::new(1, 10).into_iter();
         assert!(!r.any(|x| x == 15));
     }
 
     #[test]
     fn test_range_iterator_all() {
-        let r = Range::new(1, 5);
+        let r = Range::new(1, 5).into_iter();
         assert!(r.clone().all(|x| x > 0));
 
-        let mut r = Range::new(1, 5);
+        let mut r = Range::new(1, 5).into_iter();
         assert!(!r.all(|x| x > 3));
     }
 
     #[test]
     fn test_range_iterator_find() {
-        let mut r = Range::new(1, 10);
+        let mut r = Range::new(1, 10).into_iter();
         assert_eq!(r.find(|x| x > &5), Some(6));
     }
 
     #[test]
     fn test_range_iterator_skip() {
-        let r = Range::new(1, 5);
+        let r = Range::new(1, 5).into_iter();
         let skipped: Vec<usize> = r.skip(2).collect();
         assert_eq!(skipped, vec![3, 4, 5]);
     }
 
     #[test]
     fn test_range_iterator_take() {
-        let r = Range::new(1, 10);
+        let r = Range::new(1, 10).into_iter();
         let taken: Vec<usize> = r.take(3).collect();
         assert_eq!(taken, vec![1, 2, 3]);
     }
@@ -335,29 +985,484 @@ mod tests {
     #[test]
     fn test_range_iterator_chain() {
         let r1 = Range::new(1, 3);
-        let r2 = Range::new(4, 6);
-        let chained: Vec<usize> = r1.chain(r2).collect();
+        let r2 = Range::new(4, 6).into_iter();
+        let chained: Vec<usize> = r1.into_iter().chain(r2).collect();
         assert_eq!(chained, vec![1, 2, 3, 4, 5, 6]);
     }
 
     #[test]
     fn test_range_iterator_zip() {
         let r1 = Range::new(1, 3);
-        let r2 = Range::new(10, 12);
-        let zipped: Vec<(usize, usize)> = r1.zip(r2).collect();
+        let r2 = Range::new(10, 12).into_iter();
+        let zipped: Vec<(usize, usize)> = r1.into_iter().zip(r2).collect();
         assert_eq!(zipped, vec![(1, 10), (2, 11), (3, 12)]);
     }
 
     #[test]
     fn test_range_iterator_position() {
-        let mut r = Range::new(1, 5);
+        let mut r = Range::new(1, 5).into_iter();
         assert_eq!(r.position(|x| x == 3), Some(2)); // 3 ist an Position 2 (0-indexed)
     }
 
     #[test]
     fn test_range_iterator_enumerate() {
-        let r = Range::new(10, 12);
+        let r = Range::new(10, 12).into_iter();
         let enumerated: Vec<(usize, usize)> = r.enumerate().collect();
         assert_eq!(enumerated, vec![(0, 10), (1, 11), (2, 12)]);
     }
+
+    #[test]
+    fn test_range_touches() {
+        assert!(Range::new(1, 3).touches(&Range::new(4, 6)));
+        assert!(Range::new(4, 6).touches(&Range::new(1, 3)));
+        assert!(!Range::new(1, 3).touches(&Range::new(3, 6)));
+        assert!(!Range::new(1, 3).touches(&Range::new(5, 6)));
+        assert!(!Range::new(0, usize::MAX).touches(&Range::new(0, 1)));
+    }
+
+    #[test]
+    fn test_range_intersect() {
+        let r = Range::new(5, 10);
+        assert_eq!(r.intersect(&Range::new(8, 20)), Some(Range::new(8, 10)));
+        assert_eq!(r.intersect(&Range::new(6, 7)), Some(Range::new(6, 7)));
+        assert_eq!(r.intersect(&Range::new(10, 10)), Some(Range::new(10, 10)));
+        assert_eq!(r.intersect(&Range::new(11, 20)), None);
+        assert_eq!(r.intersect(&Range::new(7, 6)), None);
+    }
+
+    #[test]
+    fn test_range_union() {
+        let r = Range::new(5, 10);
+        assert_eq!(r.union(&Range::new(8, 20)), Some(Range::new(5, 20)));
+        assert_eq!(r.union(&Range::new(11, 20)), Some(Range::new(5, 20)));
+        assert_eq!(r.union(&Range::new(1, 4)), Some(Range::new(1, 10)));
+        assert_eq!(r.union(&Range::new(12, 20)), None);
+        assert_eq!(r.union(&Range::new(30, 1)), Some(r));
+    }
+
+    #[test]
+    fn test_range_subtract() {
+        let pieces = |a: Range, b: Range| a.subtract(&b).collect::<Vec<_>>();
+        let r = Range::new(5, 10);
+        assert_eq!(pieces(r, Range::new(1, 3)), vec![r]);
+        assert_eq!(pieces(r, Range::new(1, 6)), vec![Range::new(7, 10)]);
+        assert_eq!(pieces(r, Range::new(9, 20)), vec![Range::new(5, 8)]);
+        assert_eq!(
+            pieces(r, Range::new(7, 8)),
+            vec![Range::new(5, 6), Range::new(9, 10)]
+        );
+        assert_eq!(pieces(r, Range::new(5, 10)), vec![]);
+        assert_eq!(pieces(Range::new(3, 1), Range::new(5, 6)), vec![]);
+        assert_eq!(
+            pieces(Range::new(0, usize::MAX), Range::new(0, usize::MAX - 1)),
+            vec![Range::new(usize::MAX, usize::MAX)]
+        );
+    }
+
+    #[test]
+    fn test_range_split_at() {
+        let r = Range::new(5, 10);
+        assert_eq!(
+            r.split_at(7),
+            (Some(Range::new(5, 6)), Some(Range::new(7, 10)))
+        );
+        assert_eq!(r.split_at(5), (None, Some(r)));
+        assert_eq!(r.split_at(0), (None, Some(r)));
+        assert_eq!(r.split_at(11), (Some(r), None));
+        assert_eq!(r.split_at(100), (Some(r), None));
+    }
+
+    #[test]
+    fn test_range_shift() {
+        let r = Range::new(5usize, 10);
+        assert_eq!(r.shift(3), Some(Range::new(8, 13)));
+        assert_eq!(r.shift(-5), Some(Range::new(0, 5)));
+        assert_eq!(r.shift(-6), None);
+        assert_eq!(Range::new(1, usize::MAX).shift(1), None);
+    }
+
+    #[test]
+    fn test_range_signed() {
+        let r = Range::new(-5i32, 5);
+        assert_eq!(r.size(), Some(11));
+        assert!(r.contains(-5) && !r.contains(-6));
+        assert_eq!(r.shift(-10), Some(Range::new(-15, -5)));
+        assert_eq!(
+            r.split_at(0),
+            (Some(Range::new(-5, -1)), Some(Range::new(0, 5)))
+        );
+        assert_eq!(
+            Range::new(-10i64, -1)
+                .subtract(&Range::new(-7, -3))
+                .collect::<Vec<_>>(),
+            vec![Range::new(-10, -8), Range::new(-2, -1)]
+        );
+    }
+
+    #[test]
+    fn test_range_parse_negative() {
+        assert_eq!("-5-3".parse(), Ok(Range::new(-5i32, 3)));
+        assert_eq!("-5--3".parse(), Ok(Range::new(-5i32, -3)));
+        assert!("5--3".parse::<Range<i32>>().is_err());
+        assert!("-5".parse::<Range<i32>>().is_err());
+        assert!("-5-3".parse::<Range<u32>>().is_err());
+    }
+
+    #[test]
+    fn test_range_wide_sizes() {
+        assert_eq!(Range::new(u64::MIN, u64::MAX).size(), Some(1 << 64));
+        assert_eq!(Range::new(i64::MIN, i64::MAX).size(), Some(1 << 64));
+        assert_eq!(Range::new(0u128, u128::MAX - 1).size(), Some(u128::MAX));
+        assert_eq!(Range::new(i128::MIN, -1).size(), Some(1 << 127));
+        assert_eq!(Range::new(3u8, 1).size(), Some(0));
+        assert_eq!(Range::new(0u128, u128::MAX).size(), None);
+        assert_eq!(Range::new(i128::MIN, i128::MAX).size(), None);
+    }
+
+    #[test]
+    fn test_range_iterator_up_to_max() {
+        let collected: Vec<u8> = Range::new(253, u8::MAX).into_iter().collect();
+        assert_eq!(collected, vec![253, 254, 255]);
+        let collected: Vec<i8> = Range::new(i8::MIN, i8::MIN).into_iter().collect();
+        assert_eq!(collected, vec![i8::MIN]);
+    }
+
+    #[test]
+    fn test_range_from_str_notations() {
+        assert_eq!("3-5".parse(), Ok(Range::new(3usize, 5)));
+        assert_eq!("3..=5".parse(), Ok(Range::new(3usize, 5)));
+        assert_eq!("3..6".parse(), Ok(Range::new(3usize, 5)));
+        assert_eq!(Range::try_from("-5--1"), Ok(Range::new(-5i64, -1)));
+        assert_eq!(Range::try_from("-5..=-1"), Ok(Range::new(-5i64, -1)));
+        assert_eq!(Range::try_from("-5..0"), Ok(Range::new(-5i8, -1)));
+        assert_eq!("7-7".parse(), Ok(Range::new(7u8, 7)));
+    }
+
+    #[test]
+    fn test_range_from_str_empty_exclusive() {
+        assert!("5..5".parse::<Range>().unwrap().is_empty());
+        let r: Range<u8> = "0..0".parse().unwrap();
+        assert!(r.is_empty());
+        assert_eq!(r.size(), Some(0));
+    }
+
+    #[test]
+    fn test_range_from_str_errors() {
+        let err = |text: &str| text.parse::<Range<u8>>().unwrap_err();
+        assert_eq!(err(""), ParseRangeError::Empty);
+        assert_eq!(err("5"), ParseRangeError::MissingSeparator("5".into()));
+        assert_eq!(err("-5"), ParseRangeError::MissingSeparator("-5".into()));
+        assert_eq!(err("1-"), ParseRangeError::MissingBound("1-".into()));
+        assert_eq!(err("..=4"), ParseRangeError::MissingBound("..=4".into()));
+        assert_eq!(err("1-5-10").to_string(), "cannot parse \"5-10\" as u8");
+        assert_eq!(err("1..300").to_string(), "cannot parse \"300\" as u8");
+        assert_eq!(err(" 1-5").to_string(), "cannot parse \" 1\" as u8");
+        assert_eq!(err("-1-5").to_string(), "cannot parse \"-1\" as u8");
+        assert_eq!(
+            err("x").to_string(),
+            "expected \"-\", \"..\" or \"..=\" in \"x\""
+        );
+        assert_eq!(
+            err("10-5").to_string(),
+            "reversed range, 10 is greater than 5"
+        );
+    }
+
+    #[test]
+    fn test_range_parse_with_reversed() {
+        assert!(Range::<u8>::parse_with("5..2", Reversed::Reject).is_err());
+        assert_eq!(
+            Range::parse_with("5..2", Reversed::Swap),
+            Ok(Range::new(2u8, 4))
+        );
+        assert_eq!(
+            Range::parse_with("-1--3", Reversed::Swap),
+            Ok(Range::new(-3i32, -1))
+        );
+        assert_eq!(
+            Range::parse_with("5..0", Reversed::Empty),
+            Ok(Range::new(5u32, 0))
+        );
+        assert_eq!(
+            Range::parse_with("2..5", Reversed::Empty),
+            Ok(Range::new(2u32, 4))
+        );
+    }
+
+    #[test]
+    fn test_range_display_round_trip() {
+        for r in [
+            Range::new(-5i64, -1),
+            Range::new(i64::MIN, i64::MAX),
+            Range::new(0, 0),
+        ] {
+            assert_eq!(r.to_string().parse(), Ok(r));
+        }
+        assert_eq!(Range::new(3usize, 5).to_string(), "3-5");
+    }
+
+    #[test]
+    fn test_range_iterator_double_ended() {
+        let mut r = Range::new(1, 5).into_iter();
+        assert_eq!(r.next_back(), Some(5));
+        assert_eq!(r.next(), Some(1));
+        assert_eq!(r.size_hint(), (3, Some(3)));
+        assert_eq!(r.rev().collect::<Vec<usize>>(), vec![4, 3, 2]);
+
+        let down: Vec<u8> = Range::new(0, 2).into_iter().rev().collect();
+        assert_eq!(down, vec![2, 1, 0]);
+    }
+
+    #[test]
+    fn test_range_iterator_fused() {
+        let mut r = Range::new(i8::MAX, i8::MAX).into_iter();
+        assert_eq!(r.next(), Some(i8::MAX));
+        assert_eq!(r.next(), None);
+        assert_eq!(r.next_back(), None);
+        assert_eq!(r.next(), None);
+        assert_eq!(Range::new(5i16, 3).into_iter().len(), 0);
+    }
+
+    #[test]
+    fn test_range_iterator_len() {
+        assert_eq!(Range::new(u8::MIN, u8::MAX).into_iter().len(), 256);
+        assert_eq!(Range::new(i16::MIN, i16::MAX).into_iter().len(), 1 << 16);
+        #[cfg(target_pointer_width = "64")]
+        assert_eq!(Range::new(u32::MIN, u32::MAX).into_iter().len(), 1 << 32);
+        assert_eq!(
+            Range::new(0u64, u64::MAX).into_iter().size_hint(),
+            (usize::MAX, None)
+        );
+        assert_eq!(Range::new(1usize, 10).into_iter().count(), 10);
+    }
+
+    #[test]
+    fn test_range_iterator_nth() {
+        let mut r = Range::new(10i32, 20).into_iter();
+        assert_eq!(r.nth(3), Some(13));
+        assert_eq!(r.nth_back(2), Some(18));
+        assert_eq!(r.len(), 4);
+        assert_eq!(r.nth(4), None);
+        assert_eq!(r.next_back(), None);
+
+        let mut huge = Range::new(0u128, u128::MAX - 1).into_iter();
+        assert_eq!(huge.size_hint(), (usize::MAX, None));
+        assert_eq!(huge.nth(usize::MAX), Some(usize::MAX as u128));
+        assert_eq!(huge.nth_back(0), Some(u128::MAX - 1));
+    }
+
+    #[test]
+    fn test_range_iterator_all_of_u128() {
+        let mut all = Range::new(0u128, u128::MAX).into_iter();
+        assert_eq!(all.size_hint(), (usize::MAX, None));
+        assert_eq!(all.nth(5), Some(5));
+        assert_eq!(all.nth_back(5), Some(u128::MAX - 5));
+
+        let mut all = Range::new(i128::MIN, i128::MAX).into_iter();
+        assert_eq!(all.nth_back(0), Some(i128::MAX));
+        assert_eq!(all.next(), Some(i128::MIN));
+        assert_eq!(
+            all.nth(usize::MAX),
+            Some(i128::MIN + 1 + usize::MAX as i128)
+        );
+
+        let (lower, upper) = Range::new(0u128, u128::MAX).into_iter().split();
+        assert_eq!(lower.last(), Some((1 << 127) - 1));
+        assert_eq!(upper.unwrap().next(), Some(1 << 127));
+        let found = Range::new(0u128, u128::MAX)
+            .into_par_iter()
+            .find_any(|&n| n == 5);
+        assert_eq!(found, Some(5));
+    }
+
+    #[test]
+    fn test_range_iterator_step_by() {
+        let stepped: Vec<i64> = Range::new(-5, 5).into_iter().step_by(4).collect();
+        assert_eq!(stepped, vec![-5, -1, 3]);
+        let stepped: Vec<u64> = Range::new(0, u64::MAX)
+            .into_iter()
+            .step_by(1 << 62)
+            .collect();
+        assert_eq!(stepped, vec![0, 1 << 62, 2 << 62, 3 << 62]);
+    }
+
+    #[test]
+    fn test_range_iterator_by_reference() {
+        let r = Range::new(1, 3);
+        let doubled: Vec<usize> = (&r).into_iter().map(|x| x * 2).collect();
+        assert_eq!(doubled, vec![2, 4, 6]);
+        assert_eq!(r.into_iter().last(), Some(3));
+    }
+
+    #[test]
+    fn test_range_par_iter() {
+        let r = Range::new(1usize, 100_000);
+        let sum: usize = r.into_par_iter().sum();
+        assert_eq!(sum, r.into_iter().sum::<usize>());
+        assert_eq!(r.into_par_iter().filter(|n| n % 1000 == 0).count(), 100);
+        assert_eq!(Range::new(5, 3).into_par_iter().count(), 0);
+
+        let extremes: Vec<i8> = Range::new(i8::MIN, i8::MAX).into_par_iter().collect();
+        assert_eq!(
+            extremes,
+            Range::new(i8::MIN, i8::MAX).into_iter().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_range_u128() {
+        let r = Range::new(u128::MAX - 2, u128::MAX);
+        assert_eq!(r.size(), Some(3));
+        assert_eq!(r.shift(-1), Some(Range::new(u128::MAX - 3, u128::MAX - 1)));
+        assert_eq!(r.shift(1), None);
+    }
+
+    #[test]
+    fn test_range_set_signed() {
+        let set: RangeSet<i64> = [Range::new(-10, -5), Range::new(-4, 0), Range::new(5, 6)]
+            .into_iter()
+            .collect();
+        assert_eq!(set.ranges(), [Range::new(-10, 0), Range::new(5, 6)]);
+        assert!(set.contains(-7));
+        assert_eq!(
+            set.complement(Range::new(i64::MIN, i64::MAX)).ranges(),
+            [
+                Range::new(i64::MIN, -11),
+                Range::new(1, 4),
+                Range::new(7, i64::MAX)
+            ]
+        );
+    }
+
+    // Helper: set of `(start, end)` pairs
+    fn set(ranges: &[(usize, usize)]) -> RangeSet {
+        ranges
+            .iter()
+            .map(|&(start, end)| Range::new(start, end))
+            .collect()
+    }
+
+    fn pairs(set: &RangeSet) -> Vec<(usize, usize)> {
+        set.iter().map(|r| (r.start, r.end)).collect()
+    }
+
+    #[test]
+    fn test_range_set_insert_keeps_sorted_and_disjoint() {
+        let set = set(&[(10, 14), (3, 5), (16, 20), (12, 18)]);
+        assert_eq!(pairs(&set), vec![(3, 5), (10, 20)]);
+        assert_eq!(set.len(), 2);
+    }
+
+    #[test]
+    fn test_range_set_insert_merges_adjacent() {
+        assert_eq!(pairs(&set(&[(1, 3), (7, 9), (4, 6)])), vec![(1, 9)]);
+        assert_eq!(pairs(&set(&[(1, 3), (5, 6)])), vec![(1, 3), (5, 6)]);
+    }
+
+    #[test]
+    fn test_range_set_insert_spanning_many() {
+        let mut set = set(&[(1, 2), (4, 5), (7, 8), (20, 30)]);
+        set.insert(Range::new(0, 10));
+        assert_eq!(pairs(&set), vec![(0, 10), (20, 30)]);
+    }
+
+    #[test]
+    fn test_range_set_insert_ignores_empty() {
+        let set = set(&[(10, 5)]);
+        assert!(set.is_empty());
+        assert_eq!(set.size(), Some(0));
+    }
+
+    #[test]
+    fn test_range_set_insert_at_the_limits() {
+        let set = set(&[(usize::MAX - 1, usize::MAX), (0, 1), (2, 3)]);
+        assert_eq!(pairs(&set), vec![(0, 3), (usize::MAX - 1, usize::MAX)]);
+    }
+
+    #[test]
+    fn test_range_set_contains() {
+        let set = set(&[(3, 5), (10, 14)]);
+        for n in [3, 4, 5, 10, 14] {
+            assert!(set.contains(n), "{}", n);
+        }
+        for n in [0, 2, 6, 9, 15] {
+            assert!(!set.contains(n), "{}", n);
+        }
+        assert!(!RangeSet::new().contains(0));
+    }
+
+    #[test]
+    fn test_range_set_size() {
+        assert_eq!(set(&[(3, 5), (10, 14), (12, 18)]).size(), Some(12));
+
+        let halves = RangeSet::from_iter([Range::new(i128::MIN, -1), Range::new(1, i128::MAX)]);
+        assert_eq!(halves.size(), Some(u128::MAX));
+        let all = RangeSet::from_iter([Range::new(i128::MIN, 0), Range::new(1, i128::MAX)]);
+        assert_eq!(all.size(), None);
+    }
+
+    #[test]
+    fn test_range_set_union() {
+        let union = set(&[(1, 3), (10, 12)]).union(&set(&[(4, 5), (11, 20)]));
+        assert_eq!(pairs(&union), vec![(1, 5), (10, 20)]);
+    }
+
+    #[test]
+    fn test_range_set_intersection() {
+        let a = set(&[(1, 5), (10, 20)]);
+        let b = set(&[(3, 12), (15, 16), (19, 30)]);
+        assert_eq!(
+            pairs(&a.intersection(&b)),
+            vec![(3, 5), (10, 12), (15, 16), (19, 20)]
+        );
+        assert!(a.intersection(&RangeSet::new()).is_empty());
+    }
+
+    #[test]
+    fn test_range_set_difference() {
+        let a = set(&[(1, 10), (20, 30)]);
+        let b = set(&[(0, 2), (5, 6), (25, 40)]);
+        assert_eq!(pairs(&a.difference(&b)), vec![(3, 4), (7, 10), (20, 24)]);
+        assert_eq!(pairs(&a.difference(&RangeSet::new())), pairs(&a));
+        assert!(RangeSet::new().difference(&a).is_empty());
+    }
+
+    #[test]
+    fn test_range_set_remove() {
+        let mut set = set(&[(1, 10)]);
+        set.remove(Range::new(4, 6));
+        assert_eq!(pairs(&set), vec![(1, 3), (7, 10)]);
+    }
+
+    #[test]
+    fn test_range_set_complement() {
+        let set = set(&[(3, 5), (10, 14)]);
+        assert_eq!(
+            pairs(&set.complement(Range::new(0, 20))),
+            vec![(0, 2), (6, 9), (15, 20)]
+        );
+        assert_eq!(pairs(&set.complement(Range::new(4, 12))), vec![(6, 9)]);
+        assert_eq!(pairs(&set.complement(Range::new(11, 13))), vec![]);
+        assert_eq!(
+            pairs(&RangeSet::new().complement(Range::new(1, 2))),
+            vec![(1, 2)]
+        );
+    }
+
+    #[test]
+    fn test_range_set_complement_up_to_max() {
+        let set = set(&[(5, usize::MAX)]);
+        assert_eq!(
+            pairs(&set.complement(Range::new(0, usize::MAX))),
+            vec![(0, 4)]
+        );
+    }
+
+    #[test]
+    fn test_range_set_gaps() {
+        let set = set(&[(3, 5), (10, 14), (16, 20)]);
+        let gaps: Vec<Range> = set.gaps().collect();
+        assert_eq!(gaps, vec![Range::new(6, 9), Range::new(15, 15)]);
+        assert_eq!(RangeSet::<usize>::new().gaps().count(), 0);
+    }
 }
@@ -2,6 +2,16 @@ use std::collections::HashMap;
 
 /// Union-Find (Disjoint Set Union) data structure with Path Compression
 /// for efficient union and find operations.
+///
+/// ```
+/// use advent_of_code::types::union_find::UnionFind;
+///
+/// let mut clusters = UnionFind::new(4);
+/// clusters.union(0, 1);
+/// clusters.union(2, 1);
+/// assert_eq!(clusters.find(0), clusters.find(2));
+/// assert_eq!(clusters.cluster_count(), 2);
+/// ```
 pub struct UnionFind {
     parent: Vec<usize>,
 }
@@ -43,13 +53,11 @@ impl UnionFind {
     }
 
     /// Returns the number of distinct clusters
-    #[allow(unused)]
     pub fn cluster_count(&mut self) -> usize {
         self.cluster_sizes().len()
     }
 
     /// Returns all elements grouped by their cluster
-    #[allow(unused)]
     pub fn get_clusters(&mut self) -> Vec<Vec<usize>> {
         let mut clusters: HashMap<usize, Vec<usize>> = HashMap::new();
         for i in 0..self.parent.len() {
@@ -1,31 +1,68 @@
 use core::time;
-use std::fs;
+use std::{
+    env,
+    io::{self, IsTerminal},
+};
+
+use crate::{
+    bench::DayBench,
+    duration::{DURATION_WIDTH, format_duration, percent_of},
+    error::{Error, Result},
+    input::{Input, InputSource},
+    numbers,
+    solution::DayResult,
+};
+
+/// Reads the real or example input of a day from `source`
+pub fn read_input(source: &InputSource, year: u16, day: u8, example: bool) -> Result<Input> {
+    source.read(year, day, example)
+}
 
-pub fn read_input(day: u8, example: bool) -> String {
-    let filename = match example {
-        true => format!("inputs/day{:02}_example.txt", day),
-        false => format!("inputs/day{:02}.txt", day),
-    };
-    fs::read_to_string(&filename).unwrap_or_else(|_| panic!("Input file {} not found", filename))
+/// 64-bit FNV-1a hash, stable across platforms and Rust versions
+pub fn hash_input(input: &str) -> u64 {
+    input.bytes().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    })
 }
 
-pub fn read_lines(day: u8, example: bool) -> Vec<String> {
-    read_input(day, example)
+pub fn read_lines(source: &InputSource, year: u16, day: u8, example: bool) -> Result<Vec<String>> {
+    Ok(read_input(source, year, day, example)?
         .lines()
-        .map(|s| s.to_string())
-        .collect()
+        .map(str::to_string)
+        .collect())
 }
 
-#[allow(dead_code)]
-pub fn read_numbers(day: u8, example: bool) -> Vec<i64> {
-    read_lines(day, example)
-        .iter()
-        .filter_map(|s| s.parse::<i64>().ok())
-        .collect()
+pub fn split_lines(input: &str) -> Vec<String> {
+    input.lines().map(|s| s.to_string()).collect()
+}
+
+/// All numbers of an input of only numbers, whitespace and commas, see [`Input::numbers`]
+pub fn read_numbers(source: &InputSource, year: u16, day: u8, example: bool) -> Result<Vec<i64>> {
+    read_input(source, year, day, example)?.numbers()
+}
+
+pub fn read_input_and_split(
+    source: &InputSource,
+    year: u16,
+    day: u8,
+    example: bool,
+    separator: &str,
+) -> Result<Vec<String>> {
+    Ok(read_input(source, year, day, example)?
+        .split(separator)
+        .map(str::to_string)
+        .collect())
 }
 
-pub fn read_input_and_split(day: u8, example: bool, separator: &str) -> Vec<String> {
-    read_input(day, example)
+/// Splits the trimmed input at `separator`, trims every piece and drops empty ones
+///
+/// ```
+/// use advent_of_code::utils::split_input;
+///
+/// assert_eq!(split_input("11-22, 95-115,\n", ","), vec!["11-22", "95-115"]);
+/// ```
+pub fn split_input(input: &str, separator: &str) -> Vec<String> {
+    input
         .trim()
         .split(separator)
         .filter(|s| !s.trim().is_empty())
@@ -33,57 +70,203 @@ pub fn read_input_and_split(day: u8, example: bool, separator: &str) -> Vec<Stri
         .collect()
 }
 
-pub fn split_on_empty_lines(input: &[String]) -> Vec<Vec<String>> {
-    input
-        .split(|line| line.trim().is_empty())
-        .filter(|group| !group.is_empty())
-        .map(|group| group.to_vec())
-        .collect()
+const MIN_ANSWER_WIDTH: usize = 20;
+
+/// Number of slowest phases highlighted in the table
+const HIGHLIGHTED_PHASES: usize = 3;
+
+/// Prints all results as a table with a parse row and one row per part for every day.
+/// Each row shows its share of the time summed over all phases, the table ends with
+/// the wall-clock time of the run and that summed time.
+///
+/// Each answer is marked as verified, wrong or unknown, the slowest phases are highlighted.
+/// A failed day shows its error in place of the phases that could not run.
+pub fn print_table(results: &[DayResult], total: time::Duration) {
+    let parts = || results.iter().flat_map(|result| result.parts.iter());
+    let phase_times = || {
+        results.iter().flat_map(|result| {
+            std::iter::once(result.parse_time).chain(result.parts.iter().map(|part| part.time))
+        })
+    };
+
+    let answer_width = parts()
+        .map(|part| part.answer.to_string().chars().count())
+        .max()
+        .unwrap_or(0)
+        .max(MIN_ANSWER_WIDTH);
+    let cpu_time: time::Duration = phase_times().sum();
+
+    let mut times: Vec<time::Duration> = phase_times().collect();
+    times.sort_by(|a, b| b.cmp(a));
+    let slow_threshold = match times.len() {
+        0 | 1 => None,
+        n => Some(times[HIGHLIGHTED_PHASES.min(n) - 1]),
+    };
+    let color = use_color();
+
+    let header = format!(
+        "| Day  | {:<width$} |   | {:>dw$} | {:>6} |",
+        "Part",
+        "Duration",
+        "%",
+        width = answer_width + 8,
+        dw = DURATION_WIDTH
+    );
+    let border = format!("+{}+", "-".repeat(header.len() - 2));
+    let divider = format!("|------+{}|", "-".repeat(header.len() - 9));
+
+    println!("{}", border);
+    println!("{}", header);
+    println!("{}", border);
+
+    let format_timing = |time: time::Duration| {
+        let timing = format!(
+            "{:>dw$} | {:>5.1}%",
+            format_duration(time),
+            percent_of(time, cpu_time),
+            dw = DURATION_WIDTH
+        );
+        match slow_threshold {
+            Some(threshold) if color && time >= threshold => {
+                format!("\x1b[1;31m{}\x1b[0m", timing)
+            }
+            _ => timing,
+        }
+    };
+
+    let print_error = |day: &str, error: &Error| {
+        let message = format!("Error: {}", error);
+        for (i, line) in wrap_message(&message, header.len() - 11).iter().enumerate() {
+            let line = match color {
+                true => format!("\x1b[1;31m{}\x1b[0m", line),
+                false => line.clone(),
+            };
+            let day = if i == 0 { day } else { "" };
+            println!("|  {:<2}  | {} |", day, line);
+        }
+    };
+
+    for result in results {
+        // without parsed input there are no phases to show
+        if let Some(error) = result.error.as_ref().filter(|error| error.part.is_none()) {
+            print_error(&result.day.to_string(), error);
+            println!("{}", border);
+            continue;
+        }
+
+        println!(
+            "|  {:<2}  | {:<width$} |   | {} |",
+            result.day,
+            "Parse",
+            format_timing(result.parse_time),
+            width = answer_width + 8
+        );
+        for part in &result.parts {
+            println!("{}", divider);
+            println!(
+                "|      | Part {}: {:<width$} | {} | {} |",
+                part.part,
+                part.answer,
+                part.verification.symbol(),
+                format_timing(part.time),
+                width = answer_width
+            );
+        }
+        if let Some(error) = &result.error {
+            println!("{}", divider);
+            print_error("", error);
+        }
+        println!("{}", border);
+    }
+
+    for (label, time) in [("Wall clock", total), ("CPU (parse + solve)", cpu_time)] {
+        println!(
+            "|      | {:<width$} |   | {:>dw$} | {:>6} |",
+            label,
+            format_duration(time),
+            "",
+            width = answer_width + 8,
+            dw = DURATION_WIDTH
+        );
+    }
+    println!("{}", border);
+}
+
+/// Colors are only used on a terminal and can be disabled with `NO_COLOR`
+fn use_color() -> bool {
+    io::stdout().is_terminal() && env::var_os("NO_COLOR").is_none()
 }
 
-pub fn convert_to_matrix(input: &[String]) -> Vec<Vec<char>> {
-    input.iter().map(|line| line.chars().collect()).collect()
+/// Splits `message` into lines of exactly `width` characters so it fits a table cell,
+/// breaking at spaces where possible and inside words that are longer than a line
+fn wrap_message(message: &str, width: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut line = String::new();
+    for word in message.split_whitespace() {
+        let mut word: Vec<char> = word.chars().collect();
+        let used = line.chars().count();
+        if used > 0 && used + 1 + word.len() > width {
+            lines.push(std::mem::take(&mut line));
+        } else if used > 0 {
+            line.push(' ');
+        }
+        while word.len() > width {
+            lines.push(word.drain(..width).collect());
+        }
+        line.extend(word);
+    }
+    if !line.is_empty() || lines.is_empty() {
+        lines.push(line);
+    }
+    lines
+        .into_iter()
+        .map(|line| format!("{:<width$}", line))
+        .collect()
 }
 
-pub fn print_grid_header() {
-    println!("+---------------------------------------------------+");
-    println!("| Day  | Part                         | Duration    |");
-    println!("+---------------------------------------------------+");
+const BENCH_HEADER: &str =
+    "| Day  | Phase  |   Runs |        Min |     Median |       Mean |        p95 |    Std dev |";
+
+fn print_bench_separator() {
+    println!("+{}+", "-".repeat(BENCH_HEADER.len() - 2));
 }
 
-pub fn print_grid(
-    day: u8,
-    solution1: usize,
-    solution2: usize,
-    time1: time::Duration,
-    time2: time::Duration,
-) {
-    println!(
-        "|  {:<2}  | Part 1: {:<20} |  {:>3}.{:03} ms |",
-        day,
-        solution1,
-        time1.subsec_millis(),
-        time1.subsec_micros() % 1000
-    );
-    println!("|------+--------------------------------------------|");
-    println!(
-        "|      | Part 2: {:<20} |  {:>3}.{:03} ms |",
-        solution2,
-        time2.subsec_millis(),
-        time2.subsec_micros() % 1000
-    );
-    println!("+---------------------------------------------------+");
+pub fn print_bench_header() {
+    print_bench_separator();
+    println!("{}", BENCH_HEADER);
+    print_bench_separator();
 }
 
-pub fn print_time(time: time::Duration) {
-    println!(
-        "|      |                              | {:>4}.{:03} ms |",
-        time.subsec_millis(),
-        time.subsec_micros() % 1000
-    );
-    println!("+---------------------------------------------------+");
+/// Prints the statistics of every benchmarked phase of a day
+pub fn print_bench(bench: &DayBench) {
+    for (i, (phase, stats)) in bench.phases.iter().enumerate() {
+        let day = match i {
+            0 => format!("{:<2}", bench.day),
+            _ => "  ".to_string(),
+        };
+        println!(
+            "|  {}  | {:<6} | {:>6} | {:>10} | {:>10} | {:>10} | {:>10} | {:>10} |",
+            day,
+            phase,
+            stats.runs,
+            format_duration(stats.min),
+            format_duration(stats.median),
+            format_duration(stats.mean),
+            format_duration(stats.p95),
+            format_duration(stats.std_dev)
+        );
+    }
+    print_bench_separator();
 }
 
+/// Text between the first `open` and the following `close`, without the delimiters
+///
+/// ```
+/// use advent_of_code::utils::find_between;
+///
+/// assert_eq!(find_between("[.##.] (3) {3,5}", '{', '}'), Some("3,5"));
+/// assert_eq!(find_between("[.##.]", '{', '}'), None);
+/// ```
 pub fn find_between(hay: &str, open: char, close: char) -> Option<&str> {
     let mut start_opt = None;
     for (i, ch) in hay.char_indices() {
@@ -101,253 +284,187 @@ pub fn find_between(hay: &str, open: char, close: char) -> Option<&str> {
     }
     None
 }
-/// parse "1,2,3" into Vec<usize> [1, 2, 3]
-pub fn parse_numbers(slice: &str) -> Vec<usize> {
-    slice
-        .split(',')
-        .map(str::trim)
-        .filter_map(|t| t.parse::<usize>().ok())
-        .collect()
+/// parse "1,2,3" into `Vec<usize>` [1, 2, 3], anything but numbers, whitespace and commas
+/// is a parse error, see [`integers_strict`](numbers::integers_strict)
+///
+/// ```
+/// use advent_of_code::utils::parse_numbers;
+///
+/// assert_eq!(parse_numbers("1, 2,3").unwrap(), vec![1, 2, 3]);
+/// assert!(parse_numbers("1, 2,x,3").is_err());
+/// ```
+pub fn parse_numbers(slice: &str) -> Result<Vec<usize>> {
+    numbers::integers_strict(slice)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::fs;
 
-    // Helper: Create test input file
-    fn create_test_input_file(day: u8, content: &str) {
-        let _ = fs::create_dir_all("inputs");
-        let filename = format!("inputs/day{:02}.txt", day);
-        fs::write(&filename, content).expect("Error writing test file");
-    }
+    const YEAR: u16 = 2025;
 
-    // Helper: Delete test input file
-    fn cleanup_test_file(day: u8) {
-        let filename = format!("inputs/day{:02}.txt", day);
-        let _ = fs::remove_file(&filename);
+    // Helper: In-memory source with the real input of a single day
+    fn source_with_input(day: u8, content: &str) -> InputSource {
+        InputSource::memory().with_input(YEAR, day, false, content)
     }
 
     #[test]
     fn test_read_input_success() {
         let day = 99;
         let content = "Hello\nWorld";
-        create_test_input_file(day, content);
+        let source = source_with_input(day, content);
 
-        let result = read_input(day, false);
+        let result = read_input(&source, YEAR, day, false).unwrap();
 
-        assert_eq!(result, "Hello\nWorld");
-        cleanup_test_file(day);
+        assert_eq!(result.as_str(), "Hello\nWorld");
     }
 
     #[test]
-    #[should_panic(expected = "Input file")]
     fn test_read_input_file_not_found() {
         let day = 98;
-        cleanup_test_file(day);
-        let _ = read_input(day, false);
+        let source = source_with_input(99, "Hello");
+        let err = read_input(&source, YEAR, day, false).unwrap_err();
+        assert!(err.to_string().contains("2025/day98.txt"));
     }
 
     #[test]
     fn test_read_lines_basic() {
         let day = 97;
         let content = "line1\nline2\nline3";
-        create_test_input_file(day, content);
+        let source = source_with_input(day, content);
 
-        let lines = read_lines(day, false);
+        let lines = read_lines(&source, YEAR, day, false).unwrap();
 
         assert_eq!(lines.len(), 3);
         assert_eq!(lines[0], "line1");
         assert_eq!(lines[1], "line2");
         assert_eq!(lines[2], "line3");
-        cleanup_test_file(day);
     }
 
     #[test]
     fn test_read_lines_empty_file() {
         let day = 96;
         let content = "";
-        create_test_input_file(day, content);
+        let source = source_with_input(day, content);
 
-        let lines = read_lines(day, false);
+        let lines = read_lines(&source, YEAR, day, false).unwrap();
 
         assert_eq!(lines.len(), 0);
-        cleanup_test_file(day);
     }
 
     #[test]
     fn test_read_lines_single_line() {
         let day = 95;
         let content = "only one line";
-        create_test_input_file(day, content);
+        let source = source_with_input(day, content);
 
-        let lines = read_lines(day, false);
+        let lines = read_lines(&source, YEAR, day, false).unwrap();
 
         assert_eq!(lines.len(), 1);
         assert_eq!(lines[0], "only one line");
-        cleanup_test_file(day);
     }
 
     #[test]
     fn test_read_input_and_split_comma() {
         let day = 94;
         let content = "apple,banana,cherry";
-        create_test_input_file(day, content);
+        let source = source_with_input(day, content);
 
-        let parts = read_input_and_split(day, false, ",");
+        let parts = read_input_and_split(&source, YEAR, day, false, ",").unwrap();
 
         assert_eq!(parts.len(), 3);
         assert_eq!(parts[0], "apple");
         assert_eq!(parts[1], "banana");
         assert_eq!(parts[2], "cherry");
-        cleanup_test_file(day);
     }
 
     #[test]
     fn test_read_input_and_split_with_whitespace() {
         let day = 93;
         let content = "  apple , banana , cherry  ";
-        create_test_input_file(day, content);
+        let source = source_with_input(day, content);
 
-        let parts = read_input_and_split(day, false, ",");
+        let parts = read_input_and_split(&source, YEAR, day, false, ",").unwrap();
 
         assert_eq!(parts.len(), 3);
         assert_eq!(parts[0], "apple");
         assert_eq!(parts[1], "banana");
         assert_eq!(parts[2], "cherry");
-        cleanup_test_file(day);
     }
 
     #[test]
     fn test_read_input_and_split_custom_separator() {
         let day = 92;
         let content = "one;two;three";
-        create_test_input_file(day, content);
+        let source = source_with_input(day, content);
 
-        let parts = read_input_and_split(day, false, ";");
+        let parts = read_input_and_split(&source, YEAR, day, false, ";").unwrap();
 
         assert_eq!(parts.len(), 3);
         assert_eq!(parts[0], "one");
         assert_eq!(parts[1], "two");
         assert_eq!(parts[2], "three");
-        cleanup_test_file(day);
     }
 
     #[test]
     fn test_read_numbers_parsing() {
         let day = 91;
-        let content = "123\n456\nnot a number\n789";
-        create_test_input_file(day, content);
+        let content = "123\n456\n789";
+        let source = source_with_input(day, content);
 
-        let numbers = read_numbers(day, false);
+        let numbers = read_numbers(&source, YEAR, day, false).unwrap();
 
         assert_eq!(numbers.len(), 3);
         assert_eq!(numbers[0], 123);
         assert_eq!(numbers[1], 456);
         assert_eq!(numbers[2], 789);
-        cleanup_test_file(day);
     }
 
     #[test]
     fn test_read_numbers_negative() {
         let day = 90;
         let content = "-123\n456\n-789";
-        create_test_input_file(day, content);
+        let source = source_with_input(day, content);
 
-        let numbers = read_numbers(day, false);
+        let numbers = read_numbers(&source, YEAR, day, false).unwrap();
 
         assert_eq!(numbers.len(), 3);
         assert_eq!(numbers[0], -123);
         assert_eq!(numbers[1], 456);
         assert_eq!(numbers[2], -789);
-        cleanup_test_file(day);
     }
 
     #[test]
     fn test_read_numbers_empty_file() {
         let day = 89;
-        let content = "not numbers\nat all";
-        create_test_input_file(day, content);
+        let source = source_with_input(day, "");
 
-        let numbers = read_numbers(day, false);
+        let numbers = read_numbers(&source, YEAR, day, false).unwrap();
 
         assert_eq!(numbers.len(), 0);
-        cleanup_test_file(day);
     }
 
     #[test]
-    fn test_split_on_empty_lines_basic() {
-        let input = vec![
-            "line1".to_string(),
-            "line2".to_string(),
-            "".to_string(),
-            "line3".to_string(),
-            "line4".to_string(),
-        ];
-
-        let groups = split_on_empty_lines(&input);
-
-        assert_eq!(groups.len(), 2);
-        assert_eq!(groups[0].len(), 2);
-        assert_eq!(groups[1].len(), 2);
-        assert_eq!(groups[0], vec!["line1".to_string(), "line2".to_string()]);
-        assert_eq!(groups[1], vec!["line3".to_string(), "line4".to_string()]);
-    }
-
-    #[test]
-    fn test_split_on_empty_lines_multiple_groups() {
-        let input = vec![
-            "a".to_string(),
-            "b".to_string(),
-            "".to_string(),
-            "c".to_string(),
-            "".to_string(),
-            "d".to_string(),
-            "e".to_string(),
-        ];
-
-        let groups = split_on_empty_lines(&input);
-
-        assert_eq!(groups.len(), 3);
-        assert_eq!(groups[0].len(), 2);
-        assert_eq!(groups[1].len(), 1);
-        assert_eq!(groups[2].len(), 2);
-    }
-
-    #[test]
-    fn test_split_on_empty_lines_whitespace() {
-        let input = vec!["line1".to_string(), "   ".to_string(), "line2".to_string()];
+    fn test_read_numbers_invalid() {
+        let day = 88;
+        let content = "123\n456\nnot a number\n789";
+        let source = source_with_input(day, content);
 
-        let groups = split_on_empty_lines(&input);
+        let err = read_numbers(&source, YEAR, day, false).unwrap_err();
 
-        assert_eq!(groups.len(), 2);
-        assert_eq!(groups[0], vec!["line1".to_string()]);
-        assert_eq!(groups[1], vec!["line2".to_string()]);
+        assert_eq!(err, Error::parse(3, 1, "unexpected \"n\""));
     }
 
     #[test]
-    fn test_split_on_empty_lines_no_groups() {
-        let input = vec!["line1".to_string(), "line2".to_string()];
-        let groups = split_on_empty_lines(&input);
-
-        assert_eq!(groups.len(), 1);
-        assert_eq!(groups[0].len(), 2);
+    fn test_hash_input_known_values() {
+        assert_eq!(hash_input(""), 0xcbf29ce484222325);
+        assert_eq!(hash_input("a"), 0xaf63dc4c8601ec8c);
+        assert_eq!(hash_input("foobar"), 0x85944171f73967e8);
     }
 
     #[test]
-    fn test_split_on_empty_lines_empty_input() {
-        let input: Vec<String> = vec![];
-        let groups = split_on_empty_lines(&input);
-
-        assert_eq!(groups.len(), 0);
-    }
-
-    #[test]
-    fn test_split_on_empty_lines_only_empty() {
-        let input = vec!["".to_string(), "".to_string()];
-        let groups = split_on_empty_lines(&input);
-
-        assert_eq!(groups.len(), 0);
+    fn test_hash_input_differs() {
+        assert_ne!(hash_input("1-5"), hash_input("1-6"));
     }
 
     #[test]
@@ -378,29 +495,59 @@ mod tests {
 
     #[test]
     fn test_parse_nums_basic() {
-        assert_eq!(parse_numbers("1,2,3"), vec![1usize, 2, 3]);
+        assert_eq!(parse_numbers("1,2,3").unwrap(), vec![1usize, 2, 3]);
     }
 
     #[test]
     fn test_parse_nums_with_spaces() {
-        assert_eq!(parse_numbers("  10,  20 ,30 "), vec![10usize, 20, 30]);
+        assert_eq!(
+            parse_numbers("  10,  20 ,30 ").unwrap(),
+            vec![10usize, 20, 30]
+        );
     }
 
     #[test]
     fn test_parse_nums_empty() {
-        let v: Vec<usize> = parse_numbers("");
+        let v: Vec<usize> = parse_numbers("").unwrap();
         assert!(v.is_empty());
     }
 
     #[test]
     fn test_parse_nums_invalid_tokens() {
-        // invalid tokens are filtered out
-        assert_eq!(parse_numbers("1,foo,2,bar,3"), vec![1usize, 2, 3]);
+        assert_eq!(
+            parse_numbers("1,foo,2,bar,3").unwrap_err(),
+            Error::parse(1, 3, "unexpected \"f\"")
+        );
+        assert!(parse_numbers("1,-2").is_err());
     }
 
     #[test]
     fn test_parse_nums_trailing_commas_and_single() {
-        assert_eq!(parse_numbers("4,"), vec![4usize]);
-        assert_eq!(parse_numbers("7"), vec![7usize]);
+        assert_eq!(parse_numbers("4,").unwrap(), vec![4usize]);
+        assert_eq!(parse_numbers("7").unwrap(), vec![7usize]);
+    }
+
+    #[test]
+    fn test_wrap_message() {
+        assert_eq!(wrap_message("Error: short", 15), vec!["Error: short   "]);
+        assert_eq!(
+            wrap_message("Error: the input is far too long", 12),
+            vec!["Error: the  ", "input is far", "too long    "]
+        );
+        assert_eq!(
+            wrap_message("Error: 0123456789abcdef", 8),
+            vec!["Error:  ", "01234567", "89abcdef"]
+        );
+        assert_eq!(wrap_message("", 4), vec!["    "]);
+    }
+
+    #[test]
+    fn test_print_table_with_long_error() {
+        let error = Error::parse(1, 1, "expected a number ".repeat(10));
+        let lines = wrap_message(&format!("Error: {}", error), 60);
+        assert!(lines.len() > 1);
+        assert!(lines.iter().all(|line| line.chars().count() == 60));
+
+        print_table(&[DayResult::failed(YEAR, 3, error)], time::Duration::ZERO);
     }
 }
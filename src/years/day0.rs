@@ -0,0 +1,79 @@
+use crate::{error::Result, input::Input, solution::Solution, types::answer::Answer};
+
+const YEAR: u16 = 2025;
+const DAY: u8 = 1;
+
+pub struct Day00;
+
+impl Solution for Day00 {
+    const YEAR: u16 = YEAR;
+    const DAY: u8 = DAY;
+    type Parsed = Vec<String>;
+
+    fn parse(input: &Input) -> Result<Self::Parsed> {
+        Ok(input.lines().map(str::to_string).collect())
+    }
+
+    fn part1(input: &Self::Parsed) -> Result<Answer> {
+        Ok(solve_part1(input).into())
+    }
+
+    fn part2(input: &Self::Parsed) -> Result<Answer> {
+        Ok(solve_part2(input).into())
+    }
+}
+
+fn solve_part1(_input: &[String]) -> usize {
+    0
+}
+
+fn solve_part2(_input: &[String]) -> usize {
+    0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{input::InputSource, utils};
+
+    /// Parsed example or puzzle input
+    fn read(example: bool) -> <Day00 as Solution>::Parsed {
+        let input = utils::read_input(&InputSource::default(), YEAR, DAY, example).unwrap();
+        Day00::parse(&input).unwrap()
+    }
+
+    #[test]
+    #[ignore]
+    fn example_part_1() {
+        let input = read(true);
+
+        let result = solve_part1(&input);
+        assert_eq!(result, 0);
+    }
+
+    #[test]
+    #[ignore]
+    fn solve_part_1() {
+        let input_lines = read(false);
+
+        let part1 = solve_part1(&input_lines);
+        assert_eq!(part1, 0);
+    }
+
+    #[test]
+    #[ignore]
+    fn example_part_2() {
+        let input = read(true);
+        let result = solve_part2(&input);
+        assert_eq!(result, 0);
+    }
+
+    #[test]
+    #[ignore]
+    fn solve_part_2() {
+        let input_lines = read(false);
+
+        let part2 = solve_part2(&input_lines);
+        assert_eq!(part2, 0);
+    }
+}
@@ -0,0 +1,2 @@
+// `pub mod yYYYY { pub mod dayNN; }` for every `src/years/yYYYY/dayNN.rs`, generated by build.rs
+include!(concat!(env!("OUT_DIR"), "/years.rs"));
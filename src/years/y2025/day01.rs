@@ -0,0 +1,156 @@
+use crate::{
+    error::{Error, Result},
+    input::Input,
+    parse,
+    solution::Solution,
+    types::answer::Answer,
+};
+
+const YEAR: u16 = 2025;
+const DAY: u8 = 1;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const YEAR: u16 = YEAR;
+    const DAY: u8 = DAY;
+    type Parsed = Vec<i32>;
+
+    fn parse(input: &Input) -> Result<Self::Parsed> {
+        input
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(index, line)| get_movement(index, line))
+            .collect()
+    }
+
+    fn part1(input: &Self::Parsed) -> Result<Answer> {
+        Ok(solve_part1(input).into())
+    }
+
+    fn part2(input: &Self::Parsed) -> Result<Answer> {
+        Ok(solve_part2(input).into())
+    }
+}
+
+fn solve_part1(input: &[i32]) -> i64 {
+    let mut position: i32 = 50;
+    let mut zero_count: u32 = 0;
+    for movement in input {
+        position += movement;
+        position = position.rem_euclid(100);
+        if position == 0 {
+            zero_count += 1;
+        }
+    }
+    zero_count as i64
+}
+
+fn solve_part2(input: &[i32]) -> i64 {
+    let mut position: i32 = 50;
+    let mut zero_count: u32 = 0;
+    for &movement in input {
+        position += movement;
+
+        if position <= 0 || position >= 100 {
+            let pos_abs = position.abs();
+
+            let mut additional_zeros = match position {
+                0 => 1,
+                pos if pos < 0 => (pos_abs / 100) as u32 + 1,
+                _ => (pos_abs / 100) as u32,
+            };
+
+            if position < 0 && position == -movement.abs() {
+                additional_zeros -= 1;
+            }
+
+            zero_count += additional_zeros;
+        }
+        position = position.rem_euclid(100);
+    }
+    zero_count as i64
+}
+
+/// Parses a rotation like "L68" of the 0-based line `index` into a signed movement
+fn get_movement(index: usize, line: &str) -> Result<i32> {
+    let (direction, distance): (char, i32) = parse!("{.1}{}", line.trim(), index)?;
+    let sign = match direction {
+        'L' => -1,
+        'R' => 1,
+        _ => return Err(Error::parse(index + 1, 1, "expected direction L or R")),
+    };
+    Ok(sign * distance)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::InputSource;
+    use crate::utils;
+
+    /// Parsed example or puzzle input
+    fn read(example: bool) -> <Day01 as Solution>::Parsed {
+        let input = utils::read_input(&InputSource::default(), YEAR, DAY, example).unwrap();
+        Day01::parse(&input).unwrap()
+    }
+
+    #[test]
+    #[ignore]
+    fn example_part_1() {
+        let input = read(true);
+
+        let result = solve_part1(&input);
+        assert_eq!(result, 3);
+    }
+
+    #[test]
+    #[ignore]
+    fn solve_part_1() {
+        let input_lines = read(false);
+
+        let part1 = solve_part1(&input_lines);
+        assert_eq!(part1, 1071);
+    }
+
+    #[test]
+    #[ignore]
+    fn example_part_2() {
+        let input = read(true);
+        let result = solve_part2(&input);
+        assert_eq!(result, 6);
+    }
+
+    #[test]
+    #[ignore]
+    fn solve_part_2() {
+        let input_lines = read(false);
+
+        let part2 = solve_part2(&input_lines);
+        assert_eq!(part2, 6700);
+    }
+
+    #[test]
+    fn test_parse_rotations() {
+        assert_eq!(
+            Day01::parse(&Input::from("L68\nR48\n\nL5")).unwrap(),
+            vec![-68, 48, -5]
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid_direction() {
+        let err = Day01::parse(&Input::from("L68\nX12")).unwrap_err();
+        assert_eq!(err, Error::parse(2, 1, "expected direction L or R"));
+    }
+
+    #[test]
+    fn test_parse_invalid_distance() {
+        let err = Day01::parse(&Input::from("R1x")).unwrap_err();
+        assert_eq!(
+            err,
+            Error::parse(1, 2, "field 2: cannot parse \"1x\" as i32")
+        );
+    }
+}
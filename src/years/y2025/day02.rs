@@ -0,0 +1,146 @@
+use crate::{
+    error::{Error, Result},
+    input::Input,
+    solution::Solution,
+    types::{answer::Answer, ranges::Range},
+};
+use rayon::prelude::*;
+
+const YEAR: u16 = 2025;
+const DAY: u8 = 2;
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const YEAR: u16 = YEAR;
+    const DAY: u8 = DAY;
+    type Parsed = Vec<Range>;
+
+    fn parse(input: &Input) -> Result<Self::Parsed> {
+        input
+            .lines()
+            .enumerate()
+            .flat_map(|(index, line)| {
+                line.split(',')
+                    .map(str::trim)
+                    .filter(|range| !range.is_empty())
+                    .map(move |range| {
+                        range.parse().map_err(|err| {
+                            Error::parse_at(
+                                index,
+                                line,
+                                range,
+                                format!("invalid range \"{}\": {}", range, err),
+                            )
+                        })
+                    })
+            })
+            .collect()
+    }
+
+    fn part1(input: &Self::Parsed) -> Result<Answer> {
+        Ok(solve_part1(input).into())
+    }
+
+    fn part2(input: &Self::Parsed) -> Result<Answer> {
+        Ok(solve_part2(input).into())
+    }
+}
+
+fn solve_part1(input: &[Range]) -> usize {
+    solve_with(input, is_number_repeated)
+}
+
+fn solve_part2(input: &[Range]) -> usize {
+    solve_with(input, is_number_repeated_any_size)
+}
+
+fn solve_with(input: &[Range], check_fn: fn(&usize) -> bool) -> usize {
+    input
+        .par_iter()
+        .flat_map(|r| r.into_par_iter().filter(check_fn))
+        .sum()
+}
+
+fn is_number_repeated(n: &usize) -> bool {
+    let s = n.to_string();
+    let len = s.len();
+    len.is_multiple_of(2) && has_number_repeats_of_size_fast(&s, len / 2)
+}
+
+fn is_number_repeated_any_size(n: &usize) -> bool {
+    let s = n.to_string();
+    let max_repeat_size = s.len() / 2;
+    (1..=max_repeat_size).any(|size| has_number_repeats_of_size_fast(&s, size))
+}
+
+fn has_number_repeats_of_size_fast(s: &str, size: usize) -> bool {
+    let bytes = s.as_bytes();
+
+    if !bytes.len().is_multiple_of(size) || bytes[0] == b'0' || bytes[size] == b'0' {
+        return false;
+    }
+
+    bytes.len() > size && (0..bytes.len() - size).all(|i| bytes[i] == bytes[i + size])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::InputSource;
+    use crate::utils;
+
+    /// Parsed example or puzzle input
+    fn read(example: bool) -> <Day02 as Solution>::Parsed {
+        let input = utils::read_input(&InputSource::default(), YEAR, DAY, example).unwrap();
+        Day02::parse(&input).unwrap()
+    }
+
+    #[test]
+    #[ignore]
+    fn example_part_1() {
+        let input = read(true);
+
+        let result = solve_part1(&input);
+        assert_eq!(result, 1227775554);
+    }
+
+    #[test]
+    #[ignore]
+    fn solve_part_1() {
+        let input_lines = read(false);
+
+        let part1 = solve_part1(&input_lines);
+        assert_eq!(part1, 18595663903);
+    }
+
+    #[test]
+    #[ignore]
+    fn example_part_2() {
+        let input = read(true);
+        let result = solve_part2(&input);
+        assert_eq!(result, 4174379265);
+    }
+
+    #[test]
+    #[ignore]
+    fn solve_part_2() {
+        let input_lines = read(false);
+
+        let part2 = solve_part2(&input_lines);
+        assert_eq!(part2, 19058204438);
+    }
+
+    #[test]
+    fn test_parse_reversed_range() {
+        let err = Day02::parse(&Input::from("11-22,95-115\n998-1012, 30-25")).unwrap_err();
+        assert_eq!(
+            err,
+            Error::parse(
+                2,
+                11,
+                "invalid range \"30-25\": reversed range, 30 is greater than 25"
+            )
+        );
+    }
+}
@@ -0,0 +1,135 @@
+use crate::{
+    error::{Error, Result},
+    input::Input,
+    solution::Solution,
+    types::answer::Answer,
+};
+
+const YEAR: u16 = 2025;
+const DAY: u8 = 3;
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const YEAR: u16 = YEAR;
+    const DAY: u8 = DAY;
+    type Parsed = Vec<Vec<u8>>;
+
+    fn parse(input: &Input) -> Result<Self::Parsed> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(index, line)| parse_line(index, line))
+            .collect()
+    }
+
+    fn part1(input: &Self::Parsed) -> Result<Answer> {
+        Ok(solve_part1(input).into())
+    }
+
+    fn part2(input: &Self::Parsed) -> Result<Answer> {
+        Ok(solve_part2(input).into())
+    }
+}
+
+fn solve_part1(input: &[Vec<u8>]) -> usize {
+    input
+        .iter()
+        .map(|numbers| get_highest_number_of_size(numbers, 2))
+        .sum()
+}
+
+fn solve_part2(input: &[Vec<u8>]) -> usize {
+    input
+        .iter()
+        .map(|numbers| get_highest_number_of_size(numbers, 12))
+        .sum()
+}
+
+fn parse_line(index: usize, line: &str) -> Result<Vec<u8>> {
+    line.trim_end()
+        .chars()
+        .enumerate()
+        .map(|(column, c)| {
+            c.to_digit(10).map(|d| d as u8).ok_or_else(|| {
+                Error::parse(
+                    index + 1,
+                    column + 1,
+                    format!("expected a digit, found '{}'", c),
+                )
+            })
+        })
+        .collect()
+}
+
+fn get_highest_number_of_size(numbers: &[u8], size: usize) -> usize {
+    if numbers.len() < size {
+        return 0;
+    }
+
+    let mut digits: Vec<usize> = vec![0; size];
+    let mut current_limit = 0;
+    for hi in 0..digits.len() {
+        let start = current_limit;
+        for index in start..(numbers.len() - (size - hi - 1)) {
+            if numbers[index] > numbers[digits[hi]] || (hi > 0 && digits[hi - 1] >= digits[hi]) {
+                digits[hi] = index;
+                current_limit = index + 1;
+            }
+        }
+    }
+
+    digits
+        .iter()
+        .enumerate()
+        .map(|(hi, &digit)| (numbers[digit] as usize) * 10_usize.pow((size - hi - 1) as u32))
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::InputSource;
+    use crate::utils;
+
+    /// Parsed example or puzzle input
+    fn read(example: bool) -> <Day03 as Solution>::Parsed {
+        let input = utils::read_input(&InputSource::default(), YEAR, DAY, example).unwrap();
+        Day03::parse(&input).unwrap()
+    }
+
+    #[test]
+    #[ignore]
+    fn example_part_1() {
+        let input = read(true);
+
+        let result = solve_part1(&input);
+        assert_eq!(result, 357);
+    }
+
+    #[test]
+    #[ignore]
+    fn solve_part_1() {
+        let input_lines = read(false);
+
+        let part1 = solve_part1(&input_lines);
+        assert_eq!(part1, 17109);
+    }
+
+    #[test]
+    #[ignore]
+    fn example_part_2() {
+        let input = read(true);
+        let result = solve_part2(&input);
+        assert_eq!(result, 3121910778619);
+    }
+
+    #[test]
+    #[ignore]
+    fn solve_part_2() {
+        let input_lines = read(false);
+
+        let part2 = solve_part2(&input_lines);
+        assert_eq!(part2, 169347417057382);
+    }
+}
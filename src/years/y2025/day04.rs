@@ -0,0 +1,116 @@
+use crate::{
+    error::Result,
+    input::Input,
+    solution::Solution,
+    types::{answer::Answer, grid::Grid, point3d::Point2D},
+};
+
+const YEAR: u16 = 2025;
+const DAY: u8 = 4;
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const YEAR: u16 = YEAR;
+    const DAY: u8 = DAY;
+    type Parsed = Grid<bool>;
+
+    fn parse(input: &Input) -> Result<Self::Parsed> {
+        Grid::parse(input, |_, cell| match cell {
+            b'@' => Some(true),
+            b'.' => Some(false),
+            _ => None,
+        })
+    }
+
+    fn part1(input: &Self::Parsed) -> Result<Answer> {
+        Ok(solve_part1(input).into())
+    }
+
+    fn part2(input: &Self::Parsed) -> Result<Answer> {
+        Ok(solve_part2(input).into())
+    }
+}
+
+fn solve_part1(grid: &Grid<bool>) -> usize {
+    get_points_for_removal(grid).len()
+}
+
+fn solve_part2(input: &Grid<bool>) -> usize {
+    let mut grid = input.clone();
+    let mut total_removed = 0;
+
+    loop {
+        let points = get_points_for_removal(&grid);
+        if points.is_empty() {
+            break;
+        }
+        for point in &points {
+            grid[*point] = false;
+        }
+        total_removed += points.len();
+    }
+
+    total_removed
+}
+
+/// Rolls with fewer than 4 rolls around them
+fn get_points_for_removal(grid: &Grid<bool>) -> Vec<Point2D> {
+    grid.positions(|&roll| roll)
+        .filter(|&point| count_surrounding_rolls(grid, point) < 4)
+        .collect()
+}
+
+fn count_surrounding_rolls(grid: &Grid<bool>, point: Point2D) -> usize {
+    grid.neighbours8(point)
+        .filter(|&neighbour| grid[neighbour])
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::InputSource;
+    use crate::utils;
+
+    /// Parsed example or puzzle input
+    fn read(example: bool) -> <Day04 as Solution>::Parsed {
+        let input = utils::read_input(&InputSource::default(), YEAR, DAY, example).unwrap();
+        Day04::parse(&input).unwrap()
+    }
+
+    #[test]
+    #[ignore]
+    fn example_part_1() {
+        let input = read(true);
+
+        let result = solve_part1(&input);
+        assert_eq!(result, 13);
+    }
+
+    #[test]
+    #[ignore]
+    fn solve_part_1() {
+        let input_lines = read(false);
+
+        let part1 = solve_part1(&input_lines);
+        assert_eq!(part1, 1508);
+    }
+
+    #[test]
+    #[ignore]
+    fn example_part_2() {
+        let input = read(true);
+        let result = solve_part2(&input);
+        assert_eq!(result, 43);
+    }
+
+    #[test]
+    #[ignore]
+    fn solve_part_2() {
+        let input_lines = read(false);
+
+        let part2 = solve_part2(&input_lines);
+        assert_eq!(part2, 8538);
+    }
+}
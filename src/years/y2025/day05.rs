@@ -0,0 +1,128 @@
+use crate::{
+    error::{Error, Result},
+    input::Input,
+    solution::Solution,
+    types::{answer::Answer, ranges::RangeSet},
+};
+
+const YEAR: u16 = 2025;
+const DAY: u8 = 5;
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const YEAR: u16 = YEAR;
+    const DAY: u8 = DAY;
+    type Parsed = (RangeSet, Vec<usize>);
+
+    fn parse(input: &Input) -> Result<Self::Parsed> {
+        map_to_ranges_and_numbers(input.as_str())
+    }
+
+    fn part1(input: &Self::Parsed) -> Result<Answer> {
+        Ok(solve_part1(input).into())
+    }
+
+    fn part2(input: &Self::Parsed) -> Result<Answer> {
+        Ok(solve_part2(input).into())
+    }
+}
+
+fn solve_part1((fresh, numbers): &(RangeSet, Vec<usize>)) -> usize {
+    numbers.iter().filter(|&&n| fresh.contains(n)).count()
+}
+
+fn solve_part2((fresh, _): &(RangeSet, Vec<usize>)) -> u128 {
+    fresh
+        .size()
+        .expect("a set of usize ranges holds at most 2^64 numbers")
+}
+
+/// Parses the fresh ID ranges and, after an empty line, the available ingredient IDs
+fn map_to_ranges_and_numbers(input: &str) -> Result<(RangeSet, Vec<usize>)> {
+    let mut ranges = RangeSet::new();
+    let mut numbers = Vec::new();
+    let mut in_numbers = false;
+
+    for (index, line) in input.lines().enumerate() {
+        let value = line.trim();
+        if value.is_empty() {
+            in_numbers |= !ranges.is_empty();
+            continue;
+        }
+
+        if in_numbers {
+            let number = value.parse().map_err(|_| {
+                Error::parse_at(index, line, value, format!("invalid ID \"{}\"", value))
+            })?;
+            numbers.push(number);
+        } else {
+            let range = value.parse().map_err(|err| {
+                Error::parse_at(
+                    index,
+                    line,
+                    value,
+                    format!("invalid range \"{}\": {}", value, err),
+                )
+            })?;
+            ranges.insert(range);
+        }
+    }
+
+    if !in_numbers {
+        return Err(Error::parse(
+            input.lines().count() + 1,
+            1,
+            "expected an empty line between the ranges and the IDs",
+        ));
+    }
+    Ok((ranges, numbers))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::InputSource;
+    use crate::utils;
+
+    /// Parsed example or puzzle input
+    fn read(example: bool) -> <Day05 as Solution>::Parsed {
+        let input = utils::read_input(&InputSource::default(), YEAR, DAY, example).unwrap();
+        Day05::parse(&input).unwrap()
+    }
+
+    #[test]
+    #[ignore]
+    fn example_part_1() {
+        let input = read(true);
+
+        let result = solve_part1(&input);
+        assert_eq!(result, 3);
+    }
+
+    #[test]
+    #[ignore]
+    fn solve_part_1() {
+        let input_lines = read(false);
+
+        let part1 = solve_part1(&input_lines);
+        assert_eq!(part1, 615);
+    }
+
+    #[test]
+    #[ignore]
+    fn example_part_2() {
+        let input = read(true);
+        let result = solve_part2(&input);
+        assert_eq!(result, 14);
+    }
+
+    #[test]
+    #[ignore]
+    fn solve_part_2() {
+        let input_lines = read(false);
+
+        let part2 = solve_part2(&input_lines);
+        assert_eq!(part2, 353716783056994);
+    }
+}
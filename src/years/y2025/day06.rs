@@ -0,0 +1,216 @@
+use crate::{
+    error::{Error, Result},
+    input::Input,
+    solution::Solution,
+    types::{answer::Answer, grid::Grid},
+};
+use std::iter;
+
+const YEAR: u16 = 2025;
+const DAY: u8 = 6;
+
+pub struct Day06;
+
+#[derive(Debug)]
+pub struct Worksheet {
+    /// Numbers read left to right, one list per problem
+    rows: Vec<Vec<usize>>,
+    /// Numbers read top to bottom, one list per problem
+    columns: Vec<Vec<usize>>,
+    operators: Vec<String>,
+}
+
+impl Solution for Day06 {
+    const YEAR: u16 = YEAR;
+    const DAY: u8 = DAY;
+    type Parsed = Worksheet;
+
+    fn parse(input: &Input) -> Result<Self::Parsed> {
+        parse_worksheet(&input.lines().collect::<Vec<_>>())
+    }
+
+    fn part1(input: &Self::Parsed) -> Result<Answer> {
+        Ok(solve_part1(input).into())
+    }
+
+    fn part2(input: &Self::Parsed) -> Result<Answer> {
+        Ok(solve_part2(input).into())
+    }
+}
+
+fn parse_worksheet(input: &[&str]) -> Result<Worksheet> {
+    if input.len() < 2 {
+        return Err(Error::parse(
+            input.len() + 1,
+            1,
+            "expected at least one data line and an operator line",
+        ));
+    }
+    let operators_line = input.last().unwrap();
+    let operators = operators_line
+        .split_whitespace()
+        .map(|op| match op {
+            "+" | "*" => Ok(op.to_string()),
+            _ => Err(Error::parse_at(
+                input.len() - 1,
+                operators_line,
+                op,
+                format!("unknown operator \"{}\"", op),
+            )),
+        })
+        .collect::<Result<_>>()?;
+
+    let data_rows = &input[..input.len() - 1];
+    let rows = parse_rows(data_rows)?;
+    if let Some(index) = rows.iter().position(|row| row.len() != rows[0].len()) {
+        return Err(Error::parse(
+            index + 1,
+            1,
+            format!("expected {} numbers like the first line", rows[0].len()),
+        ));
+    }
+
+    Ok(Worksheet {
+        rows: transpose_rows(&rows),
+        columns: slice_input_by_columns(data_rows),
+        operators,
+    })
+}
+
+fn solve_part1(worksheet: &Worksheet) -> usize {
+    solve_problems(&worksheet.rows, &worksheet.operators)
+}
+
+fn solve_part2(worksheet: &Worksheet) -> usize {
+    solve_problems(&worksheet.columns, &worksheet.operators)
+}
+
+fn solve_problems(problems: &[Vec<usize>], operators: &[String]) -> usize {
+    problems
+        .iter()
+        .enumerate()
+        .map(|(i, col)| calculate_row_value(col, operators.get(i).map_or("", |op| op)))
+        .sum()
+}
+
+fn parse_rows(input_lines: &[&str]) -> Result<Vec<Vec<usize>>> {
+    input_lines
+        .iter()
+        .enumerate()
+        .map(|(index, line)| {
+            line.split_whitespace()
+                .map(|s| {
+                    s.parse::<usize>().map_err(|_| {
+                        Error::parse_at(index, line, s, format!("invalid number \"{}\"", s))
+                    })
+                })
+                .collect()
+        })
+        .collect()
+}
+
+fn transpose_rows(rows: &[Vec<usize>]) -> Vec<Vec<usize>> {
+    if rows.is_empty() {
+        return vec![];
+    }
+    let cols = rows[0].len();
+    let mut out = vec![Vec::with_capacity(rows.len()); cols];
+    for r in rows {
+        for (c, &v) in r.iter().enumerate() {
+            out[c].push(v);
+        }
+    }
+    out
+}
+
+fn slice_input_by_columns(input: &[&str]) -> Vec<Vec<usize>> {
+    if input.is_empty() {
+        return vec![];
+    }
+
+    // pad the rows with spaces so every column reaches the bottom line
+    let width = input.iter().map(|s| s.len()).max().unwrap_or(0);
+    let padded = input
+        .iter()
+        .map(|row| row.bytes().chain(iter::repeat(b' ')).take(width));
+    let grid = Grid::from_rows(padded).expect("rows are padded to the same width");
+
+    let mut result: Vec<Vec<usize>> = Vec::new();
+    let mut current_column_numbers: Vec<usize> = Vec::new();
+
+    for column in grid.columns() {
+        let vertical: String = column.map(|&ch| ch as char).collect();
+
+        if vertical.trim().is_empty() {
+            if !current_column_numbers.is_empty() {
+                result.push(std::mem::take(&mut current_column_numbers));
+            } else {
+                break;
+            }
+        } else {
+            let value = vertical.trim().parse::<usize>().unwrap_or(0);
+            current_column_numbers.push(value);
+        }
+    }
+
+    if !current_column_numbers.is_empty() {
+        result.push(current_column_numbers);
+    }
+
+    result
+}
+
+fn calculate_row_value(row: &[usize], operator: &str) -> usize {
+    match operator {
+        "+" => row.iter().sum::<usize>(),
+        "*" => row.iter().product::<usize>(),
+        _ => 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{input::InputSource, utils};
+
+    /// Parsed example or puzzle input
+    fn read(example: bool) -> <Day06 as Solution>::Parsed {
+        let input = utils::read_input(&InputSource::default(), YEAR, DAY, example).unwrap();
+        Day06::parse(&input).unwrap()
+    }
+
+    #[test]
+    #[ignore]
+    fn test_part_1() {
+        let input = read(true);
+
+        let result = solve_part1(&input);
+        assert_eq!(result, 4277556);
+    }
+
+    #[test]
+    #[ignore]
+    fn test_solve_part_1() {
+        let input_lines = read(false);
+
+        let part1 = solve_part1(&input_lines);
+        assert_eq!(part1, 5782351442566);
+    }
+
+    #[test]
+    #[ignore]
+    fn test_part_2() {
+        let input = read(true);
+        let result = solve_part2(&input);
+        assert_eq!(result, 3263827);
+    }
+
+    #[test]
+    #[ignore]
+    fn test_solve_part_2() {
+        let input_lines = read(false);
+
+        let part2 = solve_part2(&input_lines);
+        assert_eq!(part2, 10194584711842);
+    }
+}
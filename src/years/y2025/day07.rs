@@ -0,0 +1,178 @@
+use crate::{
+    error::{Error, Result},
+    input::Input,
+    solution::Solution,
+    types::{answer::Answer, grid::Grid},
+};
+use std::collections::HashSet;
+
+const YEAR: u16 = 2025;
+const DAY: u8 = 7;
+
+pub struct Day07;
+
+#[derive(Debug)]
+pub struct Manifold {
+    grid: Grid<u8>,
+    start_index: usize,
+}
+
+impl Solution for Day07 {
+    const YEAR: u16 = YEAR;
+    const DAY: u8 = DAY;
+    type Parsed = Manifold;
+
+    fn parse(input: &Input) -> Result<Self::Parsed> {
+        // only splitters and empty cells are allowed below the first line
+        let grid = Grid::parse(input, |point, cell| match cell {
+            b'.' | b'^' => Some(cell),
+            b'S' if point.y == 0 => Some(cell),
+            _ => None,
+        })?;
+        let start = grid
+            .find(&b'S')
+            .ok_or_else(|| Error::parse(1, 1, "no start found in the first line"))?;
+        Ok(Manifold {
+            grid,
+            start_index: start.x as usize,
+        })
+    }
+
+    fn part1(input: &Self::Parsed) -> Result<Answer> {
+        Ok(solve_part1(input).into())
+    }
+
+    fn part2(input: &Self::Parsed) -> Result<Answer> {
+        Ok(solve_part2(input).into())
+    }
+}
+
+fn solve_part1(manifold: &Manifold) -> usize {
+    let mut used_splitters: HashSet<(usize, usize)> = HashSet::new();
+
+    let grid = &manifold.grid;
+    let width = grid.width();
+
+    let mut current_beams: HashSet<usize> = HashSet::new();
+    current_beams.insert(manifold.start_index);
+
+    for (row, grid_row) in grid.rows().enumerate().skip(1) {
+        let mut new_beams: HashSet<usize> = HashSet::new();
+        for &beam in &current_beams {
+            let cell = grid_row[beam];
+            match cell {
+                b'.' => {
+                    new_beams.insert(beam);
+                }
+                b'^' => {
+                    used_splitters.insert((row, beam));
+                    if beam > 0 {
+                        new_beams.insert(beam - 1); // left
+                    }
+                    if beam + 1 < width {
+                        new_beams.insert(beam + 1); // right
+                    }
+                }
+                other => unreachable!("unknown cell {} at row {} col {}", other, row, beam),
+            }
+        }
+        current_beams = new_beams;
+    }
+
+    used_splitters.len()
+}
+
+fn solve_part2(manifold: &Manifold) -> usize {
+    let grid = &manifold.grid;
+    let mut cache: Vec<Option<usize>> = vec![None; grid.height() * grid.width()];
+
+    traverse(1, manifold.start_index, grid, &mut cache) + 1
+}
+
+fn traverse(row: usize, beam: usize, grid: &Grid<u8>, cache: &mut [Option<usize>]) -> usize {
+    let width = grid.width();
+    if row >= grid.height() {
+        return 0;
+    }
+
+    let idx = row * width + beam;
+    if let Some(cached) = cache[idx] {
+        return cached;
+    }
+
+    let res = match grid.row(row)[beam] {
+        b'.' => traverse(row + 1, beam, grid, cache),
+        b'^' => {
+            let mut total_splitters = 1usize;
+            if beam > 0 {
+                total_splitters += traverse(row + 1, beam - 1, grid, cache);
+            }
+            if beam + 1 < width {
+                total_splitters += traverse(row + 1, beam + 1, grid, cache);
+            }
+            total_splitters
+        }
+        other => unreachable!("unknown cell {} at row {} col {}", other, row, beam),
+    };
+
+    cache[idx] = Some(res);
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{input::InputSource, utils};
+
+    /// Parsed example or puzzle input
+    fn read(example: bool) -> <Day07 as Solution>::Parsed {
+        let input = utils::read_input(&InputSource::default(), YEAR, DAY, example).unwrap();
+        Day07::parse(&input).unwrap()
+    }
+
+    #[test]
+    #[ignore]
+    fn example_part_1() {
+        let input = read(true);
+
+        let result = solve_part1(&input);
+        assert_eq!(result, 21);
+    }
+
+    #[test]
+    #[ignore]
+    fn solve_part_1() {
+        let input_lines = read(false);
+
+        let part1 = solve_part1(&input_lines);
+        assert_eq!(part1, 1590);
+    }
+
+    #[test]
+    #[ignore]
+    fn example_part_2() {
+        let input = read(true);
+        let result = solve_part2(&input);
+        assert_eq!(result, 40);
+    }
+
+    #[test]
+    #[ignore]
+    fn solve_part_2() {
+        let input_lines = read(false);
+        let part2 = solve_part2(&input_lines);
+        assert_eq!(part2, 20571740188555);
+    }
+
+    #[test]
+    fn test_parse_unknown_cell() {
+        let err = Day07::parse(&Input::from("..S..\n.....\n..^x.")).unwrap_err();
+        assert_eq!(err, Error::parse(3, 4, "unknown cell 'x'"));
+    }
+
+    #[test]
+    fn test_parse_missing_start() {
+        let err = Day07::parse(&Input::from(".....\n..^..")).unwrap_err();
+        assert_eq!(err, Error::parse(1, 1, "no start found in the first line"));
+    }
+}
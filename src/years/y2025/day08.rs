@@ -0,0 +1,142 @@
+use crate::{
+    error::{Error, Result},
+    input::Input,
+    solution::Solution,
+    types::{answer::Answer, point3d::Point3D, union_find::UnionFind},
+};
+
+const YEAR: u16 = 2025;
+const DAY: u8 = 8;
+
+pub struct Day08;
+
+#[derive(Debug)]
+pub struct Playground {
+    points: Vec<Point3D>,
+    /// All pairs of points as (distance, index, index), shortest first
+    distances: Vec<(f64, usize, usize)>,
+}
+
+impl Solution for Day08 {
+    const YEAR: u16 = YEAR;
+    const DAY: u8 = DAY;
+    type Parsed = Playground;
+
+    fn parse(input: &Input) -> Result<Self::Parsed> {
+        let points = parse_points(&input.lines().collect::<Vec<_>>())?;
+        let distances = generate_distance_list(&points);
+        Ok(Playground { points, distances })
+    }
+
+    fn part1(input: &Self::Parsed) -> Result<Answer> {
+        Ok(solve_part1(input, 1000).into())
+    }
+
+    fn part2(input: &Self::Parsed) -> Result<Answer> {
+        Ok(solve_part2(input)?.into())
+    }
+}
+
+fn solve_part1(playground: &Playground, take: usize) -> usize {
+    let mut uf = UnionFind::new(playground.points.len());
+
+    for (_, idx1, idx2) in playground.distances.iter().take(take) {
+        uf.union(*idx1, *idx2);
+    }
+
+    let mut sizes: Vec<_> = uf.cluster_sizes().values().copied().collect();
+    sizes.sort_by(|a, b| b.cmp(a));
+
+    sizes.iter().take(3).product()
+}
+
+fn solve_part2(playground: &Playground) -> Result<usize> {
+    let points = &playground.points;
+    let mut uf = UnionFind::new(points.len());
+
+    for &(_, idx1, idx2) in &playground.distances {
+        uf.union(idx1, idx2);
+
+        let sizes = uf.cluster_sizes();
+        if sizes.len() == 1 {
+            return Ok((points[idx1].x * points[idx2].x).unsigned_abs());
+        }
+    }
+
+    Err(Error::solve(
+        "at least two junction boxes are needed for a circuit",
+    ))
+}
+
+fn parse_points(input: &[&str]) -> Result<Vec<Point3D>> {
+    input
+        .iter()
+        .enumerate()
+        .map(|(index, line)| {
+            Point3D::from_string(line).ok_or_else(|| {
+                Error::parse(index + 1, 1, format!("expected X,Y,Z, found \"{}\"", line))
+            })
+        })
+        .collect()
+}
+
+fn generate_distance_list(points: &[Point3D]) -> Vec<(f64, usize, usize)> {
+    let mut distances: Vec<_> = (0..points.len())
+        .flat_map(|i| {
+            (i + 1..points.len()).map(move |j| {
+                let dist = points[i].distance_to(&points[j]);
+                (dist, i, j)
+            })
+        })
+        .collect();
+
+    distances.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal));
+    distances
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{input::InputSource, utils};
+
+    /// Parsed example or puzzle input
+    fn read(example: bool) -> <Day08 as Solution>::Parsed {
+        let input = utils::read_input(&InputSource::default(), YEAR, DAY, example).unwrap();
+        Day08::parse(&input).unwrap()
+    }
+
+    #[test]
+    #[ignore]
+    fn example_part_1() {
+        let input = read(true);
+
+        let result = solve_part1(&input, 10);
+        assert_eq!(result, 40);
+    }
+
+    #[test]
+    #[ignore]
+    fn solve_part_1() {
+        let input_lines = read(false);
+
+        let part1 = solve_part1(&input_lines, 1000);
+        assert_eq!(part1, 66912);
+    }
+
+    #[test]
+    #[ignore]
+    fn example_part_2() {
+        let input = read(true);
+        let result = solve_part2(&input).unwrap();
+        assert_eq!(result, 25272);
+    }
+
+    #[test]
+    #[ignore]
+    fn solve_part_2() {
+        let input_lines = read(false);
+
+        let part2 = solve_part2(&input_lines).unwrap();
+        assert_eq!(part2, 724454082);
+    }
+}
@@ -1,44 +1,65 @@
 use std::{
     cmp::{max, min},
     collections::HashMap,
-    time::Instant,
 };
 
-use crate::{types::point3d::Point2D, utils};
+use crate::{
+    error::{Error, Result},
+    input::Input,
+    solution::Solution,
+    types::{answer::Answer, point3d::Point2D},
+};
 
+const YEAR: u16 = 2025;
 const DAY: u8 = 9;
 
-pub fn solve() {
-    let inputs = utils::read_lines(DAY, false);
+pub struct Day09;
+
+impl Solution for Day09 {
+    const YEAR: u16 = YEAR;
+    const DAY: u8 = DAY;
+    type Parsed = Vec<Point2D>;
 
-    let start = Instant::now();
-    let part1 = solve_part1(&inputs);
-    let duration1 = start.elapsed();
+    fn parse(input: &Input) -> Result<Self::Parsed> {
+        parse_points(&input.lines().collect::<Vec<_>>())
+    }
 
-    let start = Instant::now();
-    let part2 = solve_part2(&inputs);
-    let duration2 = start.elapsed();
+    fn part1(input: &Self::Parsed) -> Result<Answer> {
+        Ok(solve_part1(input).into())
+    }
 
-    utils::print_grid(DAY, part1, part2, duration1, duration2);
+    fn part2(input: &Self::Parsed) -> Result<Answer> {
+        Ok(solve_part2(input).into())
+    }
 }
 
-fn solve_part1(input: &[String]) -> usize {
-    let points: Vec<Point2D> = input
+fn parse_points(input: &[&str]) -> Result<Vec<Point2D>> {
+    let points = input
         .iter()
-        .filter_map(|line| Point2D::from_string(line))
-        .collect();
-
-    assert_eq!(points.len(), input.len());
+        .enumerate()
+        .map(|(index, line)| {
+            Point2D::from_string(line).ok_or_else(|| {
+                Error::parse(index + 1, 1, format!("expected X,Y, found \"{}\"", line))
+            })
+        })
+        .collect::<Result<Vec<Point2D>>>()?;
+
+    if points.len() < 2 {
+        return Err(Error::parse(
+            input.len() + 1,
+            1,
+            "expected at least two red tiles",
+        ));
+    }
+    Ok(points)
+}
 
-    create_square_list(&points).last().unwrap().0
+fn solve_part1(points: &[Point2D]) -> usize {
+    create_square_list(points).last().unwrap().0
 }
 
-fn solve_part2(input: &[String]) -> usize {
-    let mut points: Vec<Point2D> = input
-        .iter()
-        .filter_map(|line| Point2D::from_string(line))
-        .collect();
-    assert_eq!(points.len(), input.len());
+fn solve_part2(input: &[Point2D]) -> usize {
+    let mut points = input.to_vec();
 
     // Allow circle by adding first point to end
     points.push(points[0]);
@@ -91,7 +112,7 @@ fn create_square_list(points: &[Point2D]) -> Vec<(usize, usize, usize)> {
         }
     }
 
-    distances.sort_by(|a, b| a.0.cmp(&b.0));
+    distances.sort_by_key(|a| a.0);
     distances
 }
 
@@ -119,10 +140,18 @@ fn get_inner_border_points(point1: Point2D, point2: Point2D) -> Vec<Point2D> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{input::InputSource, utils};
+
+    /// Parsed example or puzzle input
+    fn read(example: bool) -> <Day09 as Solution>::Parsed {
+        let input = utils::read_input(&InputSource::default(), YEAR, DAY, example).unwrap();
+        Day09::parse(&input).unwrap()
+    }
+
     #[test]
     #[ignore]
     fn example_part_1() {
-        let input = utils::read_lines(DAY, true);
+        let input = read(true);
 
         let result = solve_part1(&input);
         assert_eq!(result, 50);
@@ -131,7 +160,7 @@ mod tests {
     #[test]
     #[ignore]
     fn solve_part_1() {
-        let input_lines = utils::read_lines(DAY, false);
+        let input_lines = read(false);
 
         let part1 = solve_part1(&input_lines);
         assert_eq!(part1, 4744899849);
@@ -140,7 +169,7 @@ mod tests {
     #[test]
     #[ignore]
     fn example_part_2() {
-        let input = utils::read_lines(DAY, true);
+        let input = read(true);
         let result = solve_part2(&input);
         assert_eq!(result, 24);
     }
@@ -148,7 +177,7 @@ mod tests {
     #[test]
     #[ignore]
     fn solve_part_2() {
-        let input_lines = utils::read_lines(DAY, false);
+        let input_lines = read(false);
 
         let part2 = solve_part2(&input_lines);
         assert_eq!(part2, 1540192500);
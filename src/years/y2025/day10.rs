@@ -0,0 +1,222 @@
+use microlp::{ComparisonOp, OptimizationDirection, Problem, Variable};
+
+use crate::{
+    error::{Error, Result},
+    input::Input,
+    parse,
+    solution::Solution,
+    types::answer::Answer,
+};
+
+const YEAR: u16 = 2025;
+const DAY: u8 = 10;
+
+#[derive(Debug)]
+pub struct Machine {
+    lights: Vec<bool>,
+    buttons: Vec<Vec<usize>>,
+    joltage: Vec<usize>,
+}
+
+impl Machine {
+    /// Parses a machine like "[.##.] (3) (1,3) {3,5,4,7}" on the 0-based line `index`
+    fn from_line(index: usize, input: &str) -> Result<Self> {
+        let (lights, buttons, joltage): (&str, _, _) =
+            parse!("[{}] {[({:,})] } {{{:,}}}", input, index)?;
+
+        Ok(Machine {
+            lights: lights.chars().map(|c| c == '#').collect(),
+            buttons,
+            joltage,
+        })
+    }
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const YEAR: u16 = YEAR;
+    const DAY: u8 = DAY;
+    type Parsed = Vec<Machine>;
+
+    fn parse(input: &Input) -> Result<Self::Parsed> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(index, line)| Machine::from_line(index, line))
+            .collect()
+    }
+
+    fn part1(input: &Self::Parsed) -> Result<Answer> {
+        Ok(solve_part1(input)?.into())
+    }
+
+    fn part2(input: &Self::Parsed) -> Result<Answer> {
+        Ok(solve_part2(input)?.into())
+    }
+}
+
+fn solve_part1(machines: &[Machine]) -> Result<usize> {
+    machines
+        .iter()
+        .enumerate()
+        .map(|(index, machine)| {
+            let mut state = vec![false; machine.lights.len()];
+            traverse_machine(machine, &mut state, 0)
+                .map(|(button_sequence, _cost)| button_sequence.len())
+                .ok_or_else(|| {
+                    Error::solve(format!("no button sequence lights machine {}", index + 1))
+                })
+        })
+        .sum()
+}
+
+fn press(state: &mut [bool], button: &Vec<usize>) {
+    for &idx in button {
+        if idx < state.len() {
+            state[idx] = !state[idx];
+        }
+    }
+}
+
+fn traverse_machine(
+    machine: &Machine,
+    state: &mut Vec<bool>,
+    button: usize,
+) -> Option<(Vec<usize>, usize)> {
+    if machine.lights == *state {
+        return Some((vec![], 0));
+    } else if button >= machine.buttons.len() {
+        return None;
+    }
+
+    // First check if not pressed any button leads to solution
+    let result_no_press = traverse_machine(machine, state, button + 1);
+
+    // Then try pressing current button
+    press(state, &machine.buttons[button]);
+    let result_press = traverse_machine(machine, state, button + 1);
+    press(state, &machine.buttons[button]); // backtrack
+
+    match (result_no_press, result_press) {
+        (Some((seq, cost)), Some((mut seq2, cost2))) => {
+            if cost <= cost2 {
+                Some((seq, cost))
+            } else {
+                seq2.insert(0, button);
+                Some((seq2, cost2 + 1))
+            }
+        }
+        (Some((seq, cost)), None) => Some((seq, cost)),
+        (None, Some((mut seq2, cost2))) => {
+            seq2.insert(0, button);
+            Some((seq2, cost2 + 1))
+        }
+        (None, None) => None,
+    }
+}
+
+fn solve_part2(machines: &[Machine]) -> Result<usize> {
+    machines
+        .iter()
+        .enumerate()
+        .map(|(index, machine)| {
+            create_solution(machine).map_err(|err| {
+                Error::solve(format!(
+                    "no joltage solution for machine {}: {}",
+                    index + 1,
+                    err
+                ))
+            })
+        })
+        .sum()
+}
+
+fn create_solution(machine: &Machine) -> std::result::Result<usize, microlp::Error> {
+    let mut problem = Problem::new(OptimizationDirection::Minimize);
+    let variables = (0..machine.buttons.len())
+        .map(|_| problem.add_integer_var(1.0, (0, i32::MAX)))
+        .collect::<Vec<_>>();
+
+    for (joltage_index, joltage) in machine.joltage.iter().enumerate() {
+        let expr: Vec<(Variable, f64)> = machine
+            .buttons
+            .iter()
+            .enumerate()
+            .filter_map(|(button_index, btn)| {
+                if btn.contains(&joltage_index) {
+                    Some((variables[button_index], 1.0))
+                } else {
+                    None
+                }
+            })
+            .collect();
+        problem.add_constraint(&expr, ComparisonOp::Eq, *joltage as f64);
+    }
+    Ok(problem.solve()?.objective().round() as usize)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::InputSource;
+    use crate::utils;
+
+    /// Parsed example or puzzle input
+    fn read(example: bool) -> <Day10 as Solution>::Parsed {
+        let input = utils::read_input(&InputSource::default(), YEAR, DAY, example).unwrap();
+        Day10::parse(&input).unwrap()
+    }
+
+    #[test]
+    #[ignore]
+    fn example_part_1() {
+        let input = read(true);
+
+        let result = solve_part1(&input).unwrap();
+        assert_eq!(result, 7);
+    }
+
+    #[test]
+    #[ignore]
+    fn solve_part_1() {
+        let input_lines = read(false);
+
+        let part1 = solve_part1(&input_lines).unwrap();
+        assert_eq!(part1, 486);
+    }
+
+    #[test]
+    #[ignore]
+    fn example_part_2() {
+        let input = read(true);
+        let result = solve_part2(&input).unwrap();
+        assert_eq!(result, 33);
+    }
+
+    #[test]
+    #[ignore]
+    fn solve_part_2() {
+        let input_lines = read(false);
+
+        let part2 = solve_part2(&input_lines).unwrap();
+        assert_eq!(part2, 17820);
+    }
+
+    #[test]
+    fn test_parse_machine() {
+        let machines = Day10::parse(&Input::from("[.##.] (3) (1,3) (2) {3,5,4,7}")).unwrap();
+        assert_eq!(machines[0].lights, vec![false, true, true, false]);
+        assert_eq!(machines[0].buttons, vec![vec![3], vec![1, 3], vec![2]]);
+        assert_eq!(machines[0].joltage, vec![3, 5, 4, 7]);
+    }
+
+    #[test]
+    fn test_parse_invalid_button() {
+        let err = Day10::parse(&Input::from("[#] (0) {1}\n[#] (0,x) {1}")).unwrap_err();
+        assert_eq!(
+            err,
+            Error::parse(2, 8, "field 2: cannot parse \"x\" as usize")
+        );
+    }
+}
@@ -1,61 +1,89 @@
-use std::{collections::VecDeque, time::Instant};
+use std::collections::VecDeque;
 
-use crate::utils;
+use crate::{
+    error::{Error, Result},
+    input::Input,
+    solution::Solution,
+    types::answer::Answer,
+};
 
+const YEAR: u16 = 2025;
 const DAY: u8 = 11;
 
-pub fn solve() {
-    let inputs = utils::read_lines(DAY, false);
+pub struct Day11;
 
-    let start = Instant::now();
-    let part1 = solve_part1(&inputs, "you");
-    let duration1 = start.elapsed();
+#[derive(Debug)]
+pub struct Devices {
+    /// Device names, index 0 is always "out"
+    variables: Vec<String>,
+    /// Outputs of every device by index
+    mapping: Vec<Vec<usize>>,
+}
+
+impl Devices {
+    fn index_of(&self, name: &str) -> Result<usize> {
+        self.variables
+            .iter()
+            .position(|var| var == name)
+            .ok_or_else(|| Error::solve(format!("device {} not found", name)))
+    }
+}
+
+impl Solution for Day11 {
+    const YEAR: u16 = YEAR;
+    const DAY: u8 = DAY;
+    type Parsed = Devices;
+
+    fn parse(input: &Input) -> Result<Self::Parsed> {
+        let lines: Vec<&str> = input.lines().collect();
+        let variables = create_variables(&lines);
+        let mapping = make_mapping(&variables, &lines)?;
+        Ok(Devices { variables, mapping })
+    }
 
-    let start = Instant::now();
-    let part2 = solve_part2(&inputs);
-    let duration2 = start.elapsed();
+    fn part1(input: &Self::Parsed) -> Result<Answer> {
+        Ok(solve_part1(input, "you")?.into())
+    }
 
-    utils::print_grid(DAY, part1, part2, duration1, duration2);
+    fn part2(input: &Self::Parsed) -> Result<Answer> {
+        Ok(solve_part2(input)?.into())
+    }
 }
 
-fn create_variables(input: &[String], start: &str) -> (Vec<String>, usize) {
+fn create_variables(input: &[&str]) -> Vec<String> {
     let mut variables: Vec<String> = input
         .iter()
         .map(|line| line.split(":").collect::<Vec<&str>>()[0].to_string())
         .collect();
     variables.insert(0, "out".to_string());
-
-    let start_index = variables
-        .iter()
-        .position(|var| var == start)
-        .expect("Start variable not found");
-
-    (variables, start_index)
+    variables
 }
 
-fn make_mapping(variables: &[String], input: &[String]) -> Vec<Vec<usize>> {
+fn make_mapping(variables: &[String], input: &[&str]) -> Result<Vec<Vec<usize>>> {
     let mut mapping: Vec<Vec<usize>> = vec![vec![]; variables.len()];
 
-    for line in input {
+    for (index, line) in input.iter().enumerate() {
         let split = line.split(":").collect::<Vec<&str>>();
-        assert!(split.len() == 2, "Invalid input line: {}", line);
-        let var_index = variables
-            .iter()
-            .position(|v| v == split[0])
-            .expect("Variable not found");
-        mapping[var_index] = split[1]
+        if split.len() != 2 {
+            return Err(Error::parse(
+                index + 1,
+                1,
+                "expected a device, a colon and its outputs",
+            ));
+        }
+        // variables are in line order after "out"
+        mapping[index + 1] = split[1]
             .trim()
             .split(" ")
             .map(|dest| {
-                variables
-                    .iter()
-                    .position(|v| v == dest)
-                    .expect("Variable not found")
+                variables.iter().position(|v| v == dest).ok_or_else(|| {
+                    Error::parse_at(index, line, dest, format!("unknown device \"{}\"", dest))
+                })
             })
-            .collect();
+            .collect::<Result<_>>()?;
     }
     mapping[0] = vec![0];
-    mapping
+    Ok(mapping)
 }
 
 fn traverse(mapping: &Vec<Vec<usize>>, current: usize) -> usize {
@@ -69,10 +97,8 @@ fn traverse(mapping: &Vec<Vec<usize>>, current: usize) -> usize {
         .sum()
 }
 
-fn solve_part1(input: &[String], start: &str) -> usize {
-    let (variables, start_index) = create_variables(input, start);
-    let mapping = make_mapping(&variables, input);
-    traverse(&mapping, start_index)
+fn solve_part1(devices: &Devices, start: &str) -> Result<usize> {
+    Ok(traverse(&devices.mapping, devices.index_of(start)?))
 }
 
 #[allow(clippy::ptr_arg)]
@@ -110,27 +136,20 @@ struct Path {
     pub dac_seen: bool,
 }
 
-fn solve_part2(input: &[String]) -> usize {
-    let (variables, start_index) = create_variables(input, "svr");
-    let mapping = make_mapping(&variables, input);
-    let rev_mapping = reverse_mapping(&mapping);
+fn solve_part2(devices: &Devices) -> Result<usize> {
+    let mapping = &devices.mapping;
+    let start_index = devices.index_of("svr")?;
+    let rev_mapping = reverse_mapping(mapping);
 
-    let mut solutions: Vec<Vec<Path>> = vec![vec![]; variables.len()];
+    let mut solutions: Vec<Vec<Path>> = vec![vec![]; devices.variables.len()];
     solutions[0].push(Path {
         options: 1,
         fft_seen: false,
         dac_seen: false,
     });
 
-    let fft_variable = variables
-        .iter()
-        .position(|var| var == "fft")
-        .expect("Start variable not found");
-
-    let dac_variable = variables
-        .iter()
-        .position(|var| var == "dac")
-        .expect("Start variable not found");
+    let fft_variable = devices.index_of("fft")?;
+    let dac_variable = devices.index_of("dac")?;
 
     let mut queue: VecDeque<usize> = VecDeque::from([0]);
 
@@ -192,51 +211,73 @@ fn solve_part2(input: &[String]) -> usize {
     }
 
     if solutions[start_index].is_empty() {
-        panic!("No solution found");
+        return Err(Error::solve("no path from svr to out found"));
     }
-    solutions[start_index]
+    Ok(solutions[start_index]
         .iter()
         .filter(|path| path.fft_seen && path.dac_seen)
         .map(|path| path.options)
-        .sum()
+        .sum())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{input::InputSource, utils};
+
+    /// Parsed example or puzzle input
+    fn read(example: bool) -> <Day11 as Solution>::Parsed {
+        let input = utils::read_input(&InputSource::default(), YEAR, DAY, example).unwrap();
+        Day11::parse(&input).unwrap()
+    }
 
     #[test]
     #[ignore]
     fn example_part_1() {
-        let input = utils::read_lines(DAY, true);
+        let input = read(true);
 
-        let result = solve_part1(&input, "svr");
+        let result = solve_part1(&input, "svr").unwrap();
         assert_eq!(result, 8);
     }
 
     #[test]
     #[ignore]
     fn solve_part_1() {
-        let input_lines = utils::read_lines(DAY, false);
+        let input_lines = read(false);
 
-        let part1 = solve_part1(&input_lines, "you");
+        let part1 = solve_part1(&input_lines, "you").unwrap();
         assert_eq!(part1, 566);
     }
 
     #[test]
     #[ignore]
     fn example_part_2() {
-        let input = utils::read_lines(DAY, true);
-        let result = solve_part2(&input);
+        let input = read(true);
+        let result = solve_part2(&input).unwrap();
         assert_eq!(result, 2);
     }
 
     #[test]
     #[ignore]
     fn solve_part_2() {
-        let input_lines = utils::read_lines(DAY, false);
+        let input_lines = read(false);
 
-        let part2 = solve_part2(&input_lines);
+        let part2 = solve_part2(&input_lines).unwrap();
         assert_eq!(part2, 331837854931968);
     }
+
+    #[test]
+    fn test_parse_unknown_device() {
+        let err = Day11::parse(&Input::from("aaa: bbb\nbbb: out zzz")).unwrap_err();
+        assert_eq!(err, Error::parse(2, 10, "unknown device \"zzz\""));
+    }
+
+    #[test]
+    fn test_missing_start_device() {
+        let devices = Day11::parse(&Input::from("aaa: out")).unwrap();
+        assert_eq!(
+            solve_part1(&devices, "you").unwrap_err(),
+            Error::solve("device you not found")
+        );
+    }
 }